@@ -28,9 +28,20 @@ pub enum ASTNode {
     INTCONST {
         val: i32,
     },
+    CHARCONST {
+        val: char,
+    },
+    STRCONST {
+        val: String,
+        label: String,
+    },
     ID {
         name: String,
     },
+    ARRAY_ACCESS {
+        name: String,
+        index: Box<ASTNode>,
+    },
     BOOL {
         op: String,
         op1: Box<ASTNode>,
@@ -45,6 +56,21 @@ pub enum ASTNode {
         op1: String,
         op2: Box<ASTNode>,
     },
+    ARRAY_ASSG {
+        op1: String,
+        index: Box<ASTNode>,
+        op2: Box<ASTNode>,
+    },
+    OP_ASSG {
+        op: String,
+        op1: String,
+        op2: Box<ASTNode>,
+    },
+    INC_DEC {
+        op: String,
+        prefix: bool,
+        name: String,
+    },
     IF {
         condition: Box<ASTNode>,
         then_stmt: Box<ASTNode>,
@@ -54,9 +80,21 @@ pub enum ASTNode {
         condition: Box<ASTNode>,
         body: Box<ASTNode>,
     },
+    DO_WHILE {
+        body: Box<ASTNode>,
+        condition: Box<ASTNode>,
+    },
+    FOR {
+        init: Box<ASTNode>,
+        condition: Box<ASTNode>,
+        step: Box<ASTNode>,
+        body: Box<ASTNode>,
+    },
     RETURN {
         expr: Box<ASTNode>,
     },
+    BREAK,
+    CONTINUE,
     NULL,
 }
 
@@ -89,9 +127,21 @@ impl ASTNode {
     pub fn new_INTCONST(val: i32) -> ASTNode {
         ASTNode::INTCONST { val }
     }
+    pub fn new_CHARCONST(val: char) -> ASTNode {
+        ASTNode::CHARCONST { val }
+    }
+    pub fn new_STRCONST(val: String, label: String) -> ASTNode {
+        ASTNode::STRCONST { val, label }
+    }
     pub fn new_ID(name: String) -> ASTNode {
         ASTNode::ID { name }
     }
+    pub fn new_ARRAY_ACCESS(name: String, index: ASTNode) -> ASTNode {
+        ASTNode::ARRAY_ACCESS {
+            name,
+            index: Box::new(index),
+        }
+    }
     pub fn new_BOOL(op: String, op1: ASTNode, op2: ASTNode) -> ASTNode {
         ASTNode::BOOL {
             op,
@@ -112,6 +162,23 @@ impl ASTNode {
             op2: Box::new(op2),
         }
     }
+    pub fn new_ARRAY_ASSG(op1: String, index: ASTNode, op2: ASTNode) -> ASTNode {
+        ASTNode::ARRAY_ASSG {
+            op1,
+            index: Box::new(index),
+            op2: Box::new(op2),
+        }
+    }
+    pub fn new_OP_ASSG(op: String, op1: String, op2: ASTNode) -> ASTNode {
+        ASTNode::OP_ASSG {
+            op,
+            op1,
+            op2: Box::new(op2),
+        }
+    }
+    pub fn new_INC_DEC(op: String, prefix: bool, name: String) -> ASTNode {
+        ASTNode::INC_DEC { op, prefix, name }
+    }
     pub fn new_IF(condition: ASTNode, then_stmt: ASTNode, else_stmt: ASTNode) -> ASTNode {
         ASTNode::IF {
             condition: Box::new(condition),
@@ -125,6 +192,20 @@ impl ASTNode {
             body: Box::new(body),
         }
     }
+    pub fn new_DO_WHILE(body: ASTNode, condition: ASTNode) -> ASTNode {
+        ASTNode::DO_WHILE {
+            body: Box::new(body),
+            condition: Box::new(condition),
+        }
+    }
+    pub fn new_FOR(init: ASTNode, condition: ASTNode, step: ASTNode, body: ASTNode) -> ASTNode {
+        ASTNode::FOR {
+            init: Box::new(init),
+            condition: Box::new(condition),
+            step: Box::new(step),
+            body: Box::new(body),
+        }
+    }
     pub fn new_RETURN(expr: ASTNode) -> ASTNode {
         ASTNode::RETURN {
             expr: Box::new(expr),
@@ -194,6 +275,33 @@ impl ASTNode {
                 op2.print_format(0, false);
                 println!();
             }
+            ASTNode::ARRAY_ASSG { op1, index, op2, .. } => {
+                indent(indent_amt);
+                print!("{}[", op1);
+                index.print_format(0, false);
+                print!("] = ");
+                op2.print_format(0, false);
+                println!();
+            }
+            ASTNode::OP_ASSG { op, op1, op2, .. } => {
+                indent(indent_amt);
+                print!("{} {} ", op1, op);
+                op2.print_format(0, false);
+                println!();
+            }
+            ASTNode::INC_DEC {
+                op, prefix, name, ..
+            } => {
+                indent(indent_amt);
+                if *prefix {
+                    print!("{}{}", op, name);
+                } else {
+                    print!("{}{}", name, op);
+                }
+                if nl {
+                    println!();
+                }
+            }
             ASTNode::WHILE {
                 condition, body, ..
             } => {
@@ -205,12 +313,56 @@ impl ASTNode {
                 indent(indent_amt + 4);
                 println!("/* end WHILE */");
             }
+            ASTNode::DO_WHILE {
+                body, condition, ..
+            } => {
+                indent(indent_amt);
+                println!("do:");
+                body.print_format(n + 1, true);
+                indent(indent_amt + 4);
+                print!("while (");
+                condition.print_format(0, false);
+                println!(")");
+                indent(indent_amt + 4);
+                println!("/* end DO_WHILE */");
+            }
+            ASTNode::FOR {
+                init,
+                condition,
+                step,
+                body,
+                ..
+            } => {
+                indent(indent_amt);
+                print!("for (");
+                condition.print_format(0, false);
+                println!("):");
+                indent(indent_amt);
+                println!("init:");
+                init.print_format(n + 1, nl);
+                indent(indent_amt);
+                println!("step:");
+                step.print_format(n + 1, nl);
+                indent(indent_amt);
+                println!("body:");
+                body.print_format(n + 1, true);
+                indent(indent_amt);
+                println!("/* end FOR */");
+            }
             ASTNode::RETURN { expr, .. } => {
                 indent(indent_amt);
                 print!("return: ");
                 expr.print_format(0, false);
                 println!();
             }
+            ASTNode::BREAK => {
+                indent(indent_amt);
+                println!("break");
+            }
+            ASTNode::CONTINUE => {
+                indent(indent_amt);
+                println!("continue");
+            }
             ASTNode::EXPR_LIST { head, next, .. } => {
                 head.print_format(0, false);
                 match **next {
@@ -222,29 +374,54 @@ impl ASTNode {
             ASTNode::ID { name, .. } => {
                 print!("{}", name);
             }
+            ASTNode::ARRAY_ACCESS { name, index, .. } => {
+                print!("{}[", name);
+                index.print_format(0, false);
+                print!("]");
+            }
             ASTNode::INTCONST { val, .. } => {
                 print!("{}", val);
             }
+            ASTNode::CHARCONST { val, .. } => match *val {
+                '\n' => print!("'\\n'"),
+                '\t' => print!("'\\t'"),
+                '\0' => print!("'\\0'"),
+                '\\' | '\'' => print!("'\\{}'", *val),
+                _ => print!("'{}'", *val),
+            },
+            ASTNode::STRCONST { val, .. } => {
+                print!("\"{}\"", crate::symbol_table::escape(val));
+            }
             ASTNode::ARITH { op, op1, op2, .. } => match (*op).as_str() {
                 "UMINUS" => {
                     print!("-(");
                     op1.print_format(0, false);
                     print!(")");
                 }
-                "-" | "+" | "*" | "/" => {
-                    print!("(");
+                "~" => {
+                    print!("~(");
                     op1.print_format(0, false);
+                    print!(")");
+                }
+                "-" | "+" | "*" | "/" | "%" | "&" | "|" | "^" | "<<" | ">>" => {
+                    print!("(");
+                    op1.print_relop_operand();
                     print!(" {} ", *op);
-                    op2.print_format(0, false);
+                    op2.print_relop_operand();
                     print!(")");
                 }
                 _ => print!("({})", *op),
             },
             ASTNode::BOOL { op, op1, op2, .. } => match (*op).as_str() {
                 "==" | "!=" | ">" | ">=" | "<" | "<=" => {
-                    op1.print_format(0, false);
+                    op1.print_relop_operand();
                     print!(" {} ", *op);
-                    op2.print_format(0, false);
+                    op2.print_relop_operand();
+                }
+                "!" => {
+                    print!("!(");
+                    op1.print_format(0, false);
+                    print!(")");
                 }
                 "&&" | "||" => {
                     print!("(");
@@ -260,6 +437,24 @@ impl ASTNode {
             }
         }
     }
+
+    // this prints an operand of a binary op, with parens if it is
+    // a relational op so that the nesting stays visible
+    fn print_relop_operand(&mut self) {
+        match self {
+            ASTNode::BOOL { op, .. } => match (*op).as_str() {
+                "==" | "!=" | ">" | ">=" | "<" | "<=" => {
+                    print!("(");
+                    self.print_format(0, false);
+                    print!(")");
+                    return;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+        self.print_format(0, false);
+    }
 }
 fn indent(num: u32) {
     let mut n = num;
@@ -0,0 +1,223 @@
+/*
+ * ./src/call_graph.rs
+ * Brandon Erickson --- brandonscotterickson@gmail.com
+ * This file implements the call graph for the C-- language. The language is defined at
+ * http://www2.cs.arizona.edu/classes/cs453/fall20/PROJECT/SPEC/cminusminusspec.html#lexical
+ * This file contains the call graph built from the FUNC_CALL nodes of each FUNC_DEFN, and the
+ *  whole-program dead function elimination and unused global report that are rooted at main.
+ */
+
+use crate::ast::ASTNode;
+use crate::symbol_table::SymbolTable;
+
+/*
+ * CallNode is a single function in the call graph. Functions in C-- are
+ * identified by both their name and their number of params.
+ */
+#[derive(PartialEq, Clone, Debug)]
+pub struct CallNode {
+    pub name: String,
+    pub nparams: u32,
+    pub callees: Vec<(String, u32)>, // every (name, nargs) called from the body
+    pub reads: Vec<String>,          // every global read in the body
+    pub writes: Vec<String>,         // every global assigned in the body
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct CallGraph {
+    pub nodes: Vec<CallNode>,
+}
+
+impl CallGraph {
+    /*
+     * this is the constructor for the CallGraph struct
+     *  program: &[ASTNode] -- the FUNC_DEFNs of the whole program
+     *  symbols: &SymbolTable -- the symbol table, used to find the body vars of each function
+     */
+    pub fn new(program: &[ASTNode], symbols: &SymbolTable) -> CallGraph {
+        let mut nodes = Vec::new();
+        for root in program {
+            if let ASTNode::FUNC_DEFN { name, params, body } = root {
+                let mut node = CallNode {
+                    name: name.clone(),
+                    nparams: params.len() as u32,
+                    callees: Vec::new(),
+                    reads: Vec::new(),
+                    writes: Vec::new(),
+                };
+                let mut locals = params.clone();
+                locals.extend(symbols.fn_locals(name, node.nparams));
+                collect(body, &locals, &mut node);
+                nodes.push(node);
+            }
+        }
+        CallGraph { nodes }
+    }
+
+    /*
+     * this finds the index of a function in the graph
+     *  name: &str -- the name of the function
+     *  nparams: u32 -- the number of params of the function
+     * returns: Option<usize> -- the index, or None for builtins and undefined functions
+     */
+    pub fn find(&self, name: &str, nparams: u32) -> Option<usize> {
+        self.nodes
+            .iter()
+            .position(|n| n.name == name && n.nparams == nparams)
+    }
+
+    /*
+     * this marks every function that can be reached by calls from a root
+     *  root: usize -- the index of the root function
+     * returns: Vec<bool> -- whether each node is reachable
+     */
+    pub fn reachable(&self, root: usize) -> Vec<bool> {
+        let mut seen = vec![false; self.nodes.len()];
+        let mut stack = vec![root];
+        while let Some(curr) = stack.pop() {
+            if seen[curr] {
+                continue;
+            }
+            seen[curr] = true;
+            for (name, nargs) in &self.nodes[curr].callees {
+                if let Some(callee) = self.find(name, *nargs) {
+                    stack.push(callee);
+                }
+            }
+        }
+        seen
+    }
+}
+
+// this walks an ASTNode and records the calls and ids within it
+fn collect(node: &ASTNode, locals: &[String], out: &mut CallNode) {
+    match node {
+        ASTNode::FUNC_CALL { name, args } => {
+            out.callees.push((name.clone(), count_args(args)));
+            collect(args, locals, out);
+        }
+        ASTNode::STMT_LIST { head, next } | ASTNode::EXPR_LIST { head, next } => {
+            collect(head, locals, out);
+            collect(next, locals, out);
+        }
+        ASTNode::ID { name } => add_id(name, locals, &mut out.reads),
+        ASTNode::ARRAY_ACCESS { name, index } => {
+            add_id(name, locals, &mut out.reads);
+            collect(index, locals, out);
+        }
+        ASTNode::BOOL { op1, op2, .. } | ASTNode::ARITH { op1, op2, .. } => {
+            collect(op1, locals, out);
+            collect(op2, locals, out);
+        }
+        ASTNode::ASSG { op1, op2 } => {
+            add_id(op1, locals, &mut out.writes);
+            collect(op2, locals, out);
+        }
+        ASTNode::ARRAY_ASSG { op1, index, op2 } => {
+            add_id(op1, locals, &mut out.writes);
+            collect(index, locals, out);
+            collect(op2, locals, out);
+        }
+        ASTNode::OP_ASSG { op1, op2, .. } => {
+            add_id(op1, locals, &mut out.reads);
+            add_id(op1, locals, &mut out.writes);
+            collect(op2, locals, out);
+        }
+        ASTNode::INC_DEC { name, .. } => {
+            add_id(name, locals, &mut out.reads);
+            add_id(name, locals, &mut out.writes);
+        }
+        ASTNode::IF {
+            condition,
+            then_stmt,
+            else_stmt,
+        } => {
+            collect(condition, locals, out);
+            collect(then_stmt, locals, out);
+            collect(else_stmt, locals, out);
+        }
+        ASTNode::WHILE { condition, body } | ASTNode::DO_WHILE { body, condition } => {
+            collect(condition, locals, out);
+            collect(body, locals, out);
+        }
+        ASTNode::FOR {
+            init,
+            condition,
+            step,
+            body,
+        } => {
+            collect(init, locals, out);
+            collect(condition, locals, out);
+            collect(step, locals, out);
+            collect(body, locals, out);
+        }
+        ASTNode::RETURN { expr } => collect(expr, locals, out),
+        ASTNode::FUNC_DEFN { body, .. } => collect(body, locals, out),
+        ASTNode::INTCONST { .. }
+        | ASTNode::CHARCONST { .. }
+        | ASTNode::STRCONST { .. }
+        | ASTNode::BREAK
+        | ASTNode::CONTINUE
+        | ASTNode::NULL => {}
+    }
+}
+
+// this records an id unless it is hidden by a param or body var
+fn add_id(name: &str, locals: &[String], ids: &mut Vec<String>) {
+    if !locals.iter().any(|l| l == name) && !ids.iter().any(|i| i == name) {
+        ids.push(name.to_string());
+    }
+}
+
+// this counts the args in an EXPR_LIST
+fn count_args(args: &ASTNode) -> u32 {
+    match args {
+        ASTNode::EXPR_LIST { next, .. } => 1 + count_args(next),
+        _ => 0,
+    }
+}
+
+/*
+ * this removes every FUNC_DEFN that cannot be reached from main, and reports
+ * every global that is not used by the functions that remain. Unused globals
+ * are kept, since they are still in the symbol table. If there is no main
+ * then the program is left as is.
+ *  program: &mut Vec<ASTNode> -- the FUNC_DEFNs of the whole program
+ *  symbols: &SymbolTable -- the symbol table of the whole program
+ *  globals: &[String] -- the names of the global vars
+ */
+pub fn eliminate_dead(program: &mut Vec<ASTNode>, symbols: &SymbolTable, globals: &[String]) {
+    let graph = CallGraph::new(program, symbols);
+    let root = match graph.find("main", 0) {
+        Some(r) => r,
+        None => {
+            eprintln!("WARNING: no main function, dead code elimination skipped");
+            return;
+        }
+    };
+    let live = graph.reachable(root);
+
+    let mut index = 0;
+    program.retain(|root| match root {
+        ASTNode::FUNC_DEFN { name, .. } => {
+            let keep = live[index];
+            index += 1;
+            if !keep {
+                eprintln!("WARNING: removed unused function: {}", name);
+            }
+            keep
+        }
+        _ => true,
+    });
+
+    for g in globals {
+        let used = graph
+            .nodes
+            .iter()
+            .enumerate()
+            .any(|(i, n)| live[i] && (n.reads.contains(g) || n.writes.contains(g)));
+        if !used {
+            eprintln!("WARNING: unused global: {}", g);
+        }
+    }
+}
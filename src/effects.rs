@@ -0,0 +1,164 @@
+/*
+ * ./src/effects.rs
+ * Brandon Erickson --- brandonscotterickson@gmail.com
+ * This file implements the side-effect analysis for the C-- language. The language is defined at
+ * http://www2.cs.arizona.edu/classes/cs453/fall20/PROJECT/SPEC/cminusminusspec.html#lexical
+ * This file contains the per-function effect summaries computed over the call graph, which
+ *  optimizations can query to know whether a function is pure.
+ */
+
+use crate::call_graph::CallGraph;
+
+/*
+ * Effects is the summary of what a function, and everything it calls, can do.
+ */
+#[derive(PartialEq, Clone, Debug)]
+pub struct Effects {
+    pub name: String,
+    pub nparams: u32,
+    pub reads_globals: Vec<String>,
+    pub writes_globals: Vec<String>,
+    pub calls_println: bool, // calls println or print_string
+    pub calls_unknown: bool, // calls a function with no FUNC_DEFN other than the builtins
+    pub recursive: bool,
+}
+
+impl Effects {
+    /*
+     * a pure function only depends on its params and changes nothing,
+     * so calls to it with the same args can be evaluated once
+     */
+    pub fn is_pure(&self) -> bool {
+        self.reads_globals.is_empty() && self.is_read_only()
+    }
+
+    /*
+     * a read only function changes nothing, but may depend on globals
+     */
+    pub fn is_read_only(&self) -> bool {
+        self.writes_globals.is_empty() && !self.calls_println && !self.calls_unknown
+    }
+
+    /*
+     * this prints the summary in the same layout as the AST
+     */
+    pub fn print(&self) {
+        println!("EFFECTS: {}/{}", self.name, self.nparams);
+        println!("  reads: {}", self.reads_globals.join(", "));
+        println!("  writes: {}", self.writes_globals.join(", "));
+        println!("  println: {}", yes_no(self.calls_println));
+        println!("  unknown calls: {}", yes_no(self.calls_unknown));
+        println!("  recursive: {}", yes_no(self.recursive));
+        println!("  pure: {}", yes_no(self.is_pure()));
+        println!("/* end EFFECTS: {} */", self.name);
+    }
+}
+
+fn yes_no(b: bool) -> &'static str {
+    if b {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+/*
+ * this computes the effects of every function in the graph. The direct
+ * effects of each body are found first, then callee effects are merged
+ * into their callers until nothing changes.
+ *  graph: &CallGraph -- the call graph of the whole program
+ *  globals: &[String] -- the names of the global vars
+ * returns: Vec<Effects> -- the effects, in the same order as graph.nodes
+ */
+pub fn analyze(graph: &CallGraph, globals: &[String]) -> Vec<Effects> {
+    let mut effects = Vec::new();
+    for (i, node) in graph.nodes.iter().enumerate() {
+        let mut eff = Effects {
+            name: node.name.clone(),
+            nparams: node.nparams,
+            reads_globals: Vec::new(),
+            writes_globals: Vec::new(),
+            calls_println: false,
+            calls_unknown: false,
+            recursive: false,
+        };
+        for id in &node.reads {
+            if globals.contains(id) {
+                eff.reads_globals.push(id.clone());
+            }
+        }
+        for id in &node.writes {
+            if globals.contains(id) {
+                eff.writes_globals.push(id.clone());
+            }
+        }
+        for (name, nargs) in &node.callees {
+            match graph.find(name, *nargs) {
+                Some(callee) => {
+                    if graph.reachable(callee)[i] {
+                        eff.recursive = true;
+                    }
+                }
+                None => {
+                    if (*name == String::from("println") || *name == String::from("print_string"))
+                        && *nargs == 1
+                    {
+                        eff.calls_println = true;
+                    } else {
+                        eff.calls_unknown = true;
+                    }
+                }
+            }
+        }
+        effects.push(eff);
+    }
+
+    // merge callee effects into callers until a fixed point is reached
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (i, node) in graph.nodes.iter().enumerate() {
+            for (name, nargs) in &node.callees {
+                let callee = match graph.find(name, *nargs) {
+                    Some(c) => effects[c].clone(),
+                    None => continue,
+                };
+                let eff = &mut effects[i];
+                for g in callee.reads_globals {
+                    if !eff.reads_globals.contains(&g) {
+                        eff.reads_globals.push(g);
+                        changed = true;
+                    }
+                }
+                for g in callee.writes_globals {
+                    if !eff.writes_globals.contains(&g) {
+                        eff.writes_globals.push(g);
+                        changed = true;
+                    }
+                }
+                if callee.calls_println && !eff.calls_println {
+                    eff.calls_println = true;
+                    changed = true;
+                }
+                if callee.calls_unknown && !eff.calls_unknown {
+                    eff.calls_unknown = true;
+                    changed = true;
+                }
+            }
+        }
+    }
+    effects
+}
+
+/*
+ * this finds the effects of a single function
+ *  effects: &[Effects] -- the result of analyze
+ *  name: &str -- the name of the function
+ *  nparams: u32 -- the number of params of the function
+ * returns: Option<&Effects> -- the effects, or None for builtins and undefined functions
+ */
+pub fn lookup<'a>(effects: &'a [Effects], name: &str, nparams: u32) -> Option<&'a Effects> {
+    effects
+        .iter()
+        .find(|e| e.name == name && e.nparams == nparams)
+}
@@ -15,6 +15,13 @@
  *      [--print_ast]
  *          This dictates whether or not to print out the abstract syntax
  *          tree that will be created while parsing the file.
+ *      [--elim_dead]
+ *          This dictates whether or not functions that cannot be reached
+ *          from main are removed with a warning, and globals that are never
+ *          used are reported.
+ *      [--dump_effects]
+ *          This dictates whether or not to print out what globals each function
+ *          reads and writes, and whether it prints, recurses, or is pure.
  * It is best to run this program using cargo, so an example command-line instruction is:
  *      cargo run ./tests/t1.c --print_ast
  * which will generate the AST structure for the file located at ./tests/t1.c
@@ -31,6 +38,8 @@ use std::sync::Mutex;
 
 // need to manually define modules for each file in the directory
 mod ast;
+mod call_graph;
+mod effects;
 mod error;
 mod parser;
 mod scanner;
@@ -41,6 +50,8 @@ lazy_static! {
     pub static ref chk_decl: Mutex<bool> = Mutex::new(false);
     pub static ref print_coms: Mutex<bool> = Mutex::new(false);
     pub static ref print_ast: Mutex<bool> = Mutex::new(false);
+    pub static ref elim_dead: Mutex<bool> = Mutex::new(false);
+    pub static ref dump_effects: Mutex<bool> = Mutex::new(false);
 }
 
 // this is the main function of the program
@@ -56,6 +67,10 @@ fn main() {
         *b = con.print_ast;
         b = print_coms.lock().unwrap();
         *b = con.print_coms;
+        b = elim_dead.lock().unwrap();
+        *b = con.elim_dead;
+        b = dump_effects.lock().unwrap();
+        *b = con.dump_effects;
     }
 
     // this inits the scanner with the file and allows it to read in the entire file
@@ -76,6 +91,8 @@ struct Config {
     chk_decl: bool,
     print_ast: bool,
     print_coms: bool,
+    elim_dead: bool,
+    dump_effects: bool,
 }
 
 impl Config {
@@ -88,6 +105,8 @@ impl Config {
         let mut chk_decl_: bool = false;
         let mut print_ast_: bool = false;
         let mut print_coms_: bool = false;
+        let mut elim_dead_: bool = false;
+        let mut dump_effects_: bool = false;
         let mut i: usize = 1;
         while i < args.len() {
             if args[i] == String::from("--chk_decl") {
@@ -96,6 +115,10 @@ impl Config {
                 print_ast_ = true;
             } else if args[i] == String::from("--print_coms") {
                 print_coms_ = true;
+            } else if args[i] == String::from("--elim_dead") {
+                elim_dead_ = true;
+            } else if args[i] == String::from("--dump_effects") {
+                dump_effects_ = true;
             } else {
                 file = args[i].to_string();
             }
@@ -106,6 +129,8 @@ impl Config {
             chk_decl: chk_decl_,
             print_ast: print_ast_,
             print_coms: print_coms_,
+            elim_dead: elim_dead_,
+            dump_effects: dump_effects_,
         }
     }
 }
@@ -10,13 +10,21 @@ use lazy_static::lazy_static; // 1.4.0
 use std::sync::Mutex;
 
 use crate::ast::ASTNode;
+use crate::call_graph;
+use crate::call_graph::CallGraph;
+use crate::effects;
 use crate::error;
 use crate::scanner;
 use crate::scanner::Token;
+use crate::symbol_table;
 use crate::symbol_table::SymbolTable;
+use crate::symbol_table::Type;
 
 lazy_static! {
     pub static ref symbols: Mutex<SymbolTable> = Mutex::new(SymbolTable::init_global());
+    pub static ref program: Mutex<Vec<ASTNode>> = Mutex::new(Vec::new()); // every FUNC_DEFN parsed
+    pub static ref globals: Mutex<Vec<String>> = Mutex::new(Vec::new()); // every global var name
+    static ref loop_depth: Mutex<u32> = Mutex::new(0); // how many loops enclose the current stmt
 }
 
 /*
@@ -25,14 +33,40 @@ lazy_static! {
 pub fn parse() {
     let mut token = scanner::get_token();
 
-    if *super::chk_decl.lock().unwrap() { // must allow println to be called
+    if *super::chk_decl.lock().unwrap() { // must allow the builtins to be called
         symbols
             .lock()
             .unwrap()
-            .add_function(&mut String::from("println"), &mut 1);
+            .add_function(&mut String::from("println"), &mut 1, Type::VOID);
+        symbols
+            .lock()
+            .unwrap()
+            .add_function(&mut String::from("print_string"), &mut 1, Type::VOID);
     }
 
     prog(&mut token);
+
+    // the whole program is needed before dead functions can be removed
+    if *super::elim_dead.lock().unwrap() {
+        let mut roots = program.lock().unwrap();
+        call_graph::eliminate_dead(
+            &mut roots,
+            &symbols.lock().unwrap(),
+            &globals.lock().unwrap(),
+        );
+        if *super::print_ast.lock().unwrap() {
+            for root in roots.iter_mut() {
+                root.print();
+            }
+        }
+    }
+
+    if *super::dump_effects.lock().unwrap() {
+        let graph = CallGraph::new(&program.lock().unwrap(), &symbols.lock().unwrap());
+        for eff in effects::analyze(&graph, &globals.lock().unwrap()) {
+            eff.print();
+        }
+    }
 }
 
 
@@ -101,6 +135,20 @@ fn match_token(token: &mut Token, to_match: Token) -> Token {
                 }
             }
         }
+        Token::CHARCONST(_) => {
+            match to_match {
+                Token::CHARCONST(_) => { // in order to ignore the char value, we need to just match the type
+                    // valid
+                    let out = token.clone();
+                    *token = scanner::get_token();
+                    return out;
+                }
+                _ => {
+                    error::print_err_rule(*scanner::line.lock().unwrap(), token, "match_token");
+                    return Token::UNDEF;
+                }
+            }
+        }
         _ => {
             if *token == to_match { // we can then use PartialEq to check the rest
                 // valid
@@ -122,19 +170,24 @@ fn prog(token: &mut Token) {
 
     match token {
         Token::KW(kw) => {
-            if *kw != String::from("int") {
-                error::print_err_rule(*scanner::line.lock().unwrap(), token, "prog");
+            let ty; // void can only be the return type of a function
+            if *kw == String::from("void") {
+                match_token(token, Token::KW(String::from("void")));
+                ty = Type::VOID;
+            } else {
+                if !is_type(kw) {
+                    error::print_err_rule(*scanner::line.lock().unwrap(), token, "prog");
+                }
+                ty = mtype(token);
             }
 
-            mtype(token);
-
             let mut id: String; // need to grab the string from id
             match match_token(token, Token::ID(String::new())) {
                 Token::ID(s) => id = s,
                 _ => id = String::new(),
             }
 
-            func_var(token, &mut id);
+            func_var(token, &mut id, ty);
             prog(token);
         }
         _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "prog"),
@@ -143,30 +196,38 @@ fn prog(token: &mut Token) {
 }
 
 // this checks the rule for func_var
-fn func_var(token: &mut Token, id: &mut String) {
+fn func_var(token: &mut Token, id: &mut String, ty: Type) {
     match *token {
-        Token::SEMI | Token::COMMA => {
+        Token::SEMI | Token::COMMA | Token::LBRACKET => {
+            if ty == Type::VOID {
+                eprintln!("cannot declare a void var: {}", id);
+                error::print_err_rule(*scanner::line.lock().unwrap(), token, "func_var");
+            }
+            let size = opt_array(token);
             if *super::chk_decl.lock().unwrap() && symbols.lock().unwrap().global_var_def(id) {
                 eprintln!("cannot redefine global var: {}", id);
                 error::print_err_rule(*scanner::line.lock().unwrap(), token, "func_var");
             }
             { // need a new block to lock symbols in
-                symbols.lock().unwrap().add_global(id);
+                symbols.lock().unwrap().add_global(id, ty, size);
+                globals.lock().unwrap().push(id.clone());
             }
-            var_decl(token, true);
+            var_decl(token, true, ty);
         }
         Token::LPAREN => {
-            let mut root = func_defn(token, id);
-            if *super::print_ast.lock().unwrap() {
+            let mut root = func_defn(token, id, ty);
+            // when eliminating dead code, printing waits until the whole program is parsed
+            if *super::print_ast.lock().unwrap() && !*super::elim_dead.lock().unwrap() {
                 root.print();
             }
+            program.lock().unwrap().push(root);
         }
         _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "func_var"),
     };
 }
 
 // this checks the rule for var_decl
-fn var_decl(token: &mut Token, global: bool) {
+fn var_decl(token: &mut Token, global: bool, ty: Type) {
     match *token {
         Token::SEMI => {
             match_token(token, Token::SEMI);
@@ -176,6 +237,7 @@ fn var_decl(token: &mut Token, global: bool) {
             match_token(token, Token::COMMA);
             match match_token(token, Token::ID(String::new())) {
                 Token::ID(mut s) => {
+                    let size = opt_array(token);
                     if global {
                         // global
                         if *super::chk_decl.lock().unwrap()
@@ -189,46 +251,82 @@ fn var_decl(token: &mut Token, global: bool) {
                             );
                         }
                         { // need a new block to lock symbols in
-                            symbols.lock().unwrap().add_global(&mut s);
+                            symbols.lock().unwrap().add_global(&mut s, ty, size);
+                            globals.lock().unwrap().push(s.clone());
                         }
                     } else {
                         // body
-                        if *super::chk_decl.lock().unwrap()
-                            && symbols.lock().unwrap().body_var_param_def(&mut s)
-                        {
-                            eprintln!("cannot redefine body var: {}", s);
-                            error::print_err_rule(
-                                *scanner::line.lock().unwrap(),
-                                token,
-                                "var_decl",
-                            );
-                        }
-                        { // need a new block to lock symbols in
-                            symbols.lock().unwrap().add_body_var(&mut s);
-                        }
+                        add_body_var(token, &mut s, ty, size, "var_decl");
                     }
                 }
                 _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "var_decl"),
             }
-            var_decl(token, global);
+            var_decl(token, global, ty);
             return;
         }
         _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "var_decl"),
     }
 }
 
+// this checks if a keyword names a type
+fn is_type(kw: &String) -> bool {
+    *kw == String::from("int") || *kw == String::from("char")
+}
+
 // this checks the rule for mtype
-fn mtype(token: &mut Token) {
-    match_token(token, Token::KW(String::from("int")));
+// it also returns the Type that was matched
+fn mtype(token: &mut Token) -> Type {
+    match token {
+        Token::KW(kw) => {
+            if *kw == String::from("int") {
+                match_token(token, Token::KW(String::from("int")));
+                return Type::INT;
+            } else if *kw == String::from("char") {
+                match_token(token, Token::KW(String::from("char")));
+                return Type::CHAR;
+            }
+            error::print_err_rule(*scanner::line.lock().unwrap(), token, "mtype");
+        }
+        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "mtype"),
+    }
+    return Type::INT;
+}
+
+// this checks the rule for opt_array
+// it also returns the number of elements if the var is an array
+fn opt_array(token: &mut Token) -> Option<u32> {
+    match *token {
+        Token::LBRACKET => {
+            match_token(token, Token::LBRACKET);
+            let size = match match_token(token, Token::INTCONST(0)) {
+                Token::INTCONST(n) => n,
+                _ => 0,
+            };
+            if size <= 0 {
+                eprintln!("array size must be positive: {}", size);
+                error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_array");
+            }
+            if symbol_table::bytes(Some(size as u32)).is_none() {
+                eprintln!("array too large: {}", size);
+                error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_array");
+            }
+            match_token(token, Token::RBRACKET);
+            return Some(size as u32);
+        }
+        Token::SEMI | Token::COMMA => return None,
+        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_array"),
+    }
+    return None;
 }
 
 // this checks the rule for func_defn
-fn func_defn(token: &mut Token, id: &mut String) -> ASTNode {
+fn func_defn(token: &mut Token, id: &mut String, ret: Type) -> ASTNode {
     match *token {
         Token::LPAREN => {
             match_token(token, Token::LPAREN);
             let mut params = Vec::new();
-            opt_formals(token, &mut params);
+            let mut types = Vec::new();
+            opt_formals(token, &mut params, &mut types);
             if *super::chk_decl.lock().unwrap()
                 && symbols
                     .lock()
@@ -242,10 +340,10 @@ fn func_defn(token: &mut Token, id: &mut String) -> ASTNode {
                 symbols
                     .lock()
                     .unwrap()
-                    .add_function(id, &mut (params.len() as u32));
+                    .add_function(id, &mut (params.len() as u32), ret);
             }
-            for mut param in params.clone() {
-                symbols.lock().unwrap().add_param(&mut param);
+            for (mut param, ty) in params.clone().into_iter().zip(types) {
+                symbols.lock().unwrap().add_param(&mut param, ty);
             }
             match_token(token, Token::RPAREN);
             match_token(token, Token::LBRACE);
@@ -260,18 +358,18 @@ fn func_defn(token: &mut Token, id: &mut String) -> ASTNode {
 }
 
 // this checks the rule for opt_formals
-fn opt_formals(token: &mut Token, params: &mut Vec<String>) {
+fn opt_formals(token: &mut Token, params: &mut Vec<String>, types: &mut Vec<Type>) {
     match token {
         Token::KW(kw) => {
-            if *kw != String::from("int") {
+            if !is_type(kw) {
                 error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_formals");
             }
-            mtype(token);
+            types.push(mtype(token));
             match match_token(token, Token::ID(String::new())) {
                 Token::ID(s) => params.push(s),
                 _ => params.push(String::new()),
             }
-            formals(token, params);
+            formals(token, params, types);
             return;
         }
         Token::RPAREN => return,
@@ -280,16 +378,16 @@ fn opt_formals(token: &mut Token, params: &mut Vec<String>) {
 }
 
 // this checks the rule for formals
-fn formals(token: &mut Token, params: &mut Vec<String>) {
+fn formals(token: &mut Token, params: &mut Vec<String>, types: &mut Vec<Type>) {
     match *token {
         Token::COMMA => {
             match_token(token, Token::COMMA);
-            mtype(token);
+            types.push(mtype(token));
             match match_token(token, Token::ID(String::new())) {
                 Token::ID(s) => params.push(s),
                 _ => params.push(String::new()),
             }
-            formals(token, params);
+            formals(token, params, types);
             return;
         }
         Token::RPAREN => return,
@@ -301,47 +399,50 @@ fn formals(token: &mut Token, params: &mut Vec<String>) {
 fn opt_var_decls(token: &mut Token) {
     match token {
         Token::KW(kw) => {
-            if *kw == String::from("if")
-                || *kw == String::from("return")
-                || *kw == String::from("while")
-            {
+            if starts_stmt(kw) {
                 return;
             }
-            if *kw != String::from("int") {
+            if !is_type(kw) {
                 error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_var_decls");
             }
-            mtype(token);
+            let ty = mtype(token);
             match match_token(token, Token::ID(String::new())) {
                 Token::ID(mut s) => {
-                    if *super::chk_decl.lock().unwrap()
-                        && symbols.lock().unwrap().global_var_def(&mut s)
-                    {
-                        eprintln!("cannot redefine global var: {}", &mut s);
-                        error::print_err_rule(
-                            *scanner::line.lock().unwrap(),
-                            token,
-                            "opt_var_decls",
-                        );
-                    }
-                    { // need a new block to lock symbols in
-                        symbols.lock().unwrap().add_global(&mut s);
-                    }
+                    let size = opt_array(token);
+                    add_body_var(token, &mut s, ty, size, "opt_var_decls");
                 }
                 _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_var_decls"),
             }
-            var_decl(token, false);
+            var_decl(token, false, ty);
             opt_var_decls(token);
             return;
         }
-        Token::ID(_) | Token::LBRACE | Token::SEMI | Token::RBRACE => return,
+        Token::ID(_) | Token::INCDEC(_) | Token::LBRACE | Token::SEMI | Token::RBRACE => return,
         _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_var_decls"),
     }
 }
 
+// this adds a body var to the current function, and checks that it is not
+// a redefinition and that it still fits in the stack frame
+// rule is the name of the rule to report on an error
+fn add_body_var(token: &mut Token, id: &mut String, ty: Type, size: Option<u32>, rule: &str) {
+    if *super::chk_decl.lock().unwrap() && symbols.lock().unwrap().body_var_param_def(id) {
+        eprintln!("cannot redefine body var: {}", id);
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, rule);
+    }
+    let fits = { // need a new block to lock symbols in
+        symbols.lock().unwrap().add_body_var(id, ty, size)
+    };
+    if !fits {
+        eprintln!("body vars too large for the stack frame: {}", id);
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, rule);
+    }
+}
+
 // this checks the rule for opt_stmt_list
 fn opt_stmt_list(token: &mut Token) -> ASTNode {
     match token {
-        Token::ID(_) | Token::LBRACE | Token::SEMI => {
+        Token::ID(_) | Token::INCDEC(_) | Token::LBRACE | Token::SEMI => {
             let head = stmt(token);
             let next = opt_stmt_list(token);
             if head == ASTNode::NULL {
@@ -350,10 +451,7 @@ fn opt_stmt_list(token: &mut Token) -> ASTNode {
             return ASTNode::new_STMT_LIST(head, next);
         }
         Token::KW(kw) => {
-            if *kw == String::from("return")
-                || *kw == String::from("while")
-                || *kw == String::from("if")
-            {
+            if starts_stmt(kw) {
                 let head = stmt(token);
                 let next = opt_stmt_list(token);
                 if head == ASTNode::NULL {
@@ -369,6 +467,17 @@ fn opt_stmt_list(token: &mut Token) -> ASTNode {
     return ASTNode::NULL;
 }
 
+// this checks if a keyword begins a stmt
+fn starts_stmt(kw: &String) -> bool {
+    *kw == String::from("if")
+        || *kw == String::from("return")
+        || *kw == String::from("while")
+        || *kw == String::from("for")
+        || *kw == String::from("do")
+        || *kw == String::from("break")
+        || *kw == String::from("continue")
+}
+
 // this checks the rule for stmt
 fn stmt(token: &mut Token) -> ASTNode {
     match token {
@@ -380,6 +489,11 @@ fn stmt(token: &mut Token) -> ASTNode {
             }
             _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "stmt"),
         },
+        Token::INCDEC(_) => {
+            let node = prefix_inc_dec(token);
+            match_token(token, Token::SEMI);
+            return node;
+        }
         Token::KW(kw) => {
             if *kw == String::from("return") {
                 return return_stmt(token);
@@ -387,6 +501,12 @@ fn stmt(token: &mut Token) -> ASTNode {
                 return while_stmt(token);
             } else if *kw == String::from("if") {
                 return if_stmt(token);
+            } else if *kw == String::from("for") {
+                return for_stmt(token);
+            } else if *kw == String::from("do") {
+                return do_stmt(token);
+            } else if *kw == String::from("break") || *kw == String::from("continue") {
+                return jump_stmt(token);
             } else {
                 error::print_err_rule(*scanner::line.lock().unwrap(), token, "stmt");
             }
@@ -408,28 +528,74 @@ fn stmt(token: &mut Token) -> ASTNode {
 
 // this checks the rule for fn_or_assg
 fn fn_or_assg(token: &mut Token, id: &mut String) -> ASTNode {
-    match *token {
+    match token.clone() {
         Token::ASSG => {
-            {
-                let mut sym = symbols.lock().unwrap();
-                if *super::chk_decl.lock().unwrap()
-                    && !sym.global_var_def(id)
-                    && !sym.body_var_param_def(id)
-                {
-                    eprintln!("cannot assign to a var that has not been defined: {}", id);
-                    error::print_err_rule(*scanner::line.lock().unwrap(), token, "fn_or_assg");
+            check_assg_target(token, id, "fn_or_assg");
+            return assg_stmt(token, id.clone());
+        }
+        Token::OPASSG(op) => {
+            check_assg_target(token, id, "fn_or_assg");
+            match_token(token, Token::OPASSG(op.clone()));
+            return ASTNode::new_OP_ASSG(op, id.clone(), or_exp(token));
+        }
+        Token::INCDEC(op) => {
+            check_assg_target(token, id, "fn_or_assg");
+            match_token(token, Token::INCDEC(op.clone()));
+            return ASTNode::new_INC_DEC(op, false, id.clone());
+        }
+        Token::LBRACKET => {
+            let index = array_index(token, id);
+            match *token {
+                Token::ASSG => {
+                    match_token(token, Token::ASSG);
+                    return ASTNode::new_ARRAY_ASSG(id.clone(), index, or_exp(token));
                 }
+                _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "fn_or_assg"),
             }
-            return assg_stmt(token, id.clone());
         }
         Token::LPAREN => {
-            return fn_call(token, id);
+            return fn_call(token, id, false);
         }
         _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "fn_or_assg"),
     };
     return ASTNode::NULL;
 }
 
+// this checks that an id can be assigned to as a whole
+// rule is the name of the rule to report on an error
+fn check_assg_target(token: &mut Token, id: &mut String, rule: &str) {
+    let mut sym = symbols.lock().unwrap();
+    if *super::chk_decl.lock().unwrap()
+        && !sym.global_var_def(id)
+        && !sym.body_var_param_def(id)
+    {
+        eprintln!("cannot assign to a var that has not been defined: {}", id);
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, rule);
+    }
+    if *super::chk_decl.lock().unwrap() && sym.is_array(id) {
+        eprintln!("cannot assign to an array without an index: {}", id);
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, rule);
+    }
+}
+
+// this checks the rule for prefix_inc_dec
+fn prefix_inc_dec(token: &mut Token) -> ASTNode {
+    match token.clone() {
+        Token::INCDEC(op) => {
+            match_token(token, Token::INCDEC(op.clone()));
+            match match_token(token, Token::ID(String::new())) {
+                Token::ID(mut s) => {
+                    check_assg_target(token, &mut s, "prefix_inc_dec");
+                    return ASTNode::new_INC_DEC(op, true, s);
+                }
+                _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "prefix_inc_dec"),
+            }
+        }
+        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "prefix_inc_dec"),
+    }
+    return ASTNode::NULL;
+}
+
 // this checks the rule for if_stmt
 fn if_stmt(token: &mut Token) -> ASTNode {
     match token {
@@ -454,10 +620,7 @@ fn if_stmt(token: &mut Token) -> ASTNode {
 fn opt_else(token: &mut Token) -> ASTNode {
     match token {
         Token::KW(kw) => {
-            if *kw == String::from("if")
-                || *kw == String::from("return")
-                || *kw == String::from("while")
-            {
+            if starts_stmt(kw) {
                 return ASTNode::NULL;
             }
             if *kw != String::from("else") {
@@ -466,7 +629,9 @@ fn opt_else(token: &mut Token) -> ASTNode {
             match_token(token, Token::KW(String::from("else")));
             return stmt(token);
         }
-        Token::ID(_) | Token::LBRACE | Token::SEMI | Token::RBRACE => return ASTNode::NULL,
+        Token::ID(_) | Token::INCDEC(_) | Token::LBRACE | Token::SEMI | Token::RBRACE => {
+            return ASTNode::NULL
+        }
         _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_else"),
     };
     return ASTNode::NULL;
@@ -483,7 +648,7 @@ fn while_stmt(token: &mut Token) -> ASTNode {
             match_token(token, Token::LPAREN);
             let condition = or_exp(token);
             match_token(token, Token::RPAREN);
-            let body = stmt(token);
+            let body = loop_body(token);
             return ASTNode::new_WHILE(condition, body);
         }
         _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "while_stmt"),
@@ -491,6 +656,95 @@ fn while_stmt(token: &mut Token) -> ASTNode {
     return ASTNode::NULL;
 }
 
+// this parses the stmt that is the body of a loop, so break and continue are allowed in it
+fn loop_body(token: &mut Token) -> ASTNode {
+    *loop_depth.lock().unwrap() += 1;
+    let body = stmt(token);
+    *loop_depth.lock().unwrap() -= 1;
+    return body;
+}
+
+// this checks the rule for jump_stmt
+fn jump_stmt(token: &mut Token) -> ASTNode {
+    match token.clone() {
+        Token::KW(kw) => {
+            let node = if kw == String::from("break") {
+                ASTNode::BREAK
+            } else if kw == String::from("continue") {
+                ASTNode::CONTINUE
+            } else {
+                error::print_err_rule(*scanner::line.lock().unwrap(), token, "jump_stmt");
+                ASTNode::NULL
+            };
+            if *super::chk_decl.lock().unwrap() && *loop_depth.lock().unwrap() == 0 {
+                eprintln!("cannot {} outside of a loop", kw);
+                error::print_err_rule(*scanner::line.lock().unwrap(), token, "jump_stmt");
+            }
+            match_token(token, Token::KW(kw));
+            match_token(token, Token::SEMI);
+            return node;
+        }
+        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "jump_stmt"),
+    }
+    return ASTNode::NULL;
+}
+
+// this checks the rule for do_stmt
+fn do_stmt(token: &mut Token) -> ASTNode {
+    match token {
+        Token::KW(kw) => {
+            if *kw != String::from("do") {
+                error::print_err_rule(*scanner::line.lock().unwrap(), token, "do_stmt");
+            }
+            match_token(token, Token::KW(String::from("do")));
+            let body = loop_body(token);
+            match_token(token, Token::KW(String::from("while")));
+            match_token(token, Token::LPAREN);
+            let condition = or_exp(token);
+            match_token(token, Token::RPAREN);
+            match_token(token, Token::SEMI);
+            return ASTNode::new_DO_WHILE(body, condition);
+        }
+        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "do_stmt"),
+    }
+    return ASTNode::NULL;
+}
+
+// this checks the rule for for_stmt
+fn for_stmt(token: &mut Token) -> ASTNode {
+    match token {
+        Token::KW(kw) => {
+            if *kw != String::from("for") {
+                error::print_err_rule(*scanner::line.lock().unwrap(), token, "for_stmt");
+            }
+            match_token(token, Token::KW(String::from("for")));
+            match_token(token, Token::LPAREN);
+            let init = for_assg(token);
+            match_token(token, Token::SEMI);
+            let condition = or_exp(token);
+            match_token(token, Token::SEMI);
+            let step = for_assg(token);
+            match_token(token, Token::RPAREN);
+            let body = loop_body(token);
+            return ASTNode::new_FOR(init, condition, step, body);
+        }
+        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "for_stmt"),
+    }
+    return ASTNode::NULL;
+}
+
+// this checks the rule for for_assg
+fn for_assg(token: &mut Token) -> ASTNode {
+    if let Token::INCDEC(_) = token {
+        return prefix_inc_dec(token);
+    }
+    match match_token(token, Token::ID(String::new())) {
+        Token::ID(mut s) => return fn_or_assg(token, &mut s),
+        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "for_assg"),
+    }
+    return ASTNode::NULL;
+}
+
 // this checks the rule for return_stmt
 fn return_stmt(token: &mut Token) -> ASTNode {
     match token {
@@ -500,6 +754,19 @@ fn return_stmt(token: &mut Token) -> ASTNode {
             }
             match_token(token, Token::KW(String::from("return")));
             let expr = opt_arith_exp(token);
+            if *super::chk_decl.lock().unwrap() {
+                let ret = symbols.lock().unwrap().ret_type();
+                if ret == Type::VOID && expr != ASTNode::NULL {
+                    eprintln!("cannot return a value from a void function");
+                    error::print_err_rule(*scanner::line.lock().unwrap(), token, "return_stmt");
+                }
+                if ret != Type::VOID && expr == ASTNode::NULL {
+                    eprintln!(
+                        "WARNING: LINE {} ----- return with no value in a non-void function",
+                        *scanner::line.lock().unwrap()
+                    );
+                }
+            }
             match_token(token, Token::SEMI);
             return ASTNode::new_RETURN(expr);
         }
@@ -513,7 +780,7 @@ fn assg_stmt(token: &mut Token, id: String) -> ASTNode {
     match *token {
         Token::ASSG => {
             match_token(token, Token::ASSG);
-            return ASTNode::new_ASSG(id, addsub_exp(token));
+            return ASTNode::new_ASSG(id, or_exp(token));
         }
         _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "assg_stmt"),
     }
@@ -522,20 +789,53 @@ fn assg_stmt(token: &mut Token, id: String) -> ASTNode {
 
 // this checks the rule for opt_fn_call
 fn opt_fn_call(token: &mut Token, id: &mut String) -> ASTNode {
-    match *token {
+    match token.clone() {
         Token::LPAREN => {
-            return fn_call(token, id);
+            return fn_call(token, id, true);
+        }
+        Token::LBRACKET => {
+            let index = array_index(token, id);
+            return ASTNode::new_ARRAY_ACCESS(id.clone(), index);
+        }
+        Token::INCDEC(op) => {
+            check_assg_target(token, id, "opt_fn_call");
+            match_token(token, Token::INCDEC(op.clone()));
+            return ASTNode::new_INC_DEC(op, false, id.clone());
         }
-        Token::ARITH(_) | Token::BOOL(_) | Token::RPAREN | Token::COMMA | Token::SEMI => {
-            return ASTNode::new_ID(id.clone());
+        _ => {
+            if !follows_operand(token) {
+                error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_fn_call");
+            }
+            if *super::chk_decl.lock().unwrap() && symbols.lock().unwrap().is_array(id) {
+                eprintln!("cannot use an array without an index: {}", id);
+                error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_fn_call");
+            }
         }
-        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_fn_call"),
     }
     return ASTNode::new_ID(id.clone());
 }
 
+// this checks the rule for array_index
+fn array_index(token: &mut Token, id: &mut String) -> ASTNode {
+    match *token {
+        Token::LBRACKET => {
+            if *super::chk_decl.lock().unwrap() && !symbols.lock().unwrap().is_array(id) {
+                eprintln!("cannot index a var that is not an array: {}", id);
+                error::print_err_rule(*scanner::line.lock().unwrap(), token, "array_index");
+            }
+            match_token(token, Token::LBRACKET);
+            let index = or_exp(token);
+            match_token(token, Token::RBRACKET);
+            return index;
+        }
+        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "array_index"),
+    }
+    return ASTNode::NULL;
+}
+
 // this checks the rule for fn_call
-fn fn_call(token: &mut Token, id: &mut String) -> ASTNode {
+// as_value is whether the result of the call is used in an expression
+fn fn_call(token: &mut Token, id: &mut String, as_value: bool) -> ASTNode {
     match *token {
         Token::LPAREN => {
             match_token(token, Token::LPAREN);
@@ -550,6 +850,16 @@ fn fn_call(token: &mut Token, id: &mut String) -> ASTNode {
                 );
                 error::print_err_rule(*scanner::line.lock().unwrap(), token, "fn_call");
             }
+            if *super::chk_decl.lock().unwrap() {
+                check_string_args(token, id, &args);
+            }
+            if *super::chk_decl.lock().unwrap()
+                && as_value
+                && symbols.lock().unwrap().fn_ret_type(id, nargs) == Some(Type::VOID)
+            {
+                eprintln!("cannot use the result of a void function: {}", id);
+                error::print_err_rule(*scanner::line.lock().unwrap(), token, "fn_call");
+            }
             match_token(token, Token::RPAREN);
             return ASTNode::new_FUNC_CALL(id.clone(), args);
         }
@@ -558,28 +868,70 @@ fn fn_call(token: &mut Token, id: &mut String) -> ASTNode {
     return ASTNode::NULL;
 }
 
+// this checks that string literals are only passed to print_string,
+// and that print_string is only passed a string literal
+fn check_string_args(token: &mut Token, id: &mut String, args: &ASTNode) {
+    let mut curr = args;
+    while let ASTNode::EXPR_LIST { head, next } = curr {
+        let is_str = match **head {
+            ASTNode::STRCONST { .. } => true,
+            _ => false,
+        };
+        if *id == String::from("print_string") && !is_str {
+            eprintln!("print_string can only be passed a string literal");
+            error::print_err_rule(*scanner::line.lock().unwrap(), token, "fn_call");
+        }
+        if *id != String::from("print_string") && is_str {
+            eprintln!("a string literal can only be passed to print_string: {}", id);
+            error::print_err_rule(*scanner::line.lock().unwrap(), token, "fn_call");
+        }
+        curr = next;
+    }
+}
+
+// this checks if a token can begin an expression
+fn starts_exp(token: &Token) -> bool {
+    match token {
+        Token::ID(_)
+        | Token::INTCONST(_)
+        | Token::CHARCONST(_)
+        | Token::LPAREN
+        | Token::INCDEC(_) => true,
+        Token::ARITH(ar) => *ar == String::from("-") || *ar == String::from("~"),
+        Token::BOOL(bl) => *bl == String::from("!"),
+        _ => false,
+    }
+}
+
+// this checks if a token can follow an operand, which is either
+// an operator or a token that ends the whole expression
+fn follows_operand(token: &Token) -> bool {
+    match token {
+        Token::ARITH(_)
+        | Token::BOOL(_)
+        | Token::RPAREN
+        | Token::RBRACKET
+        | Token::COMMA
+        | Token::SEMI => true,
+        _ => false,
+    }
+}
+
 // this checks the rule for opt_expr_list
 fn opt_expr_list(token: &mut Token, nargs: &mut u32) -> ASTNode {
     match token {
-        Token::ID(_) | Token::INTCONST(_) | Token::LPAREN => {
-            let head = addsub_exp(token);
-            *nargs = *nargs + 1;
-            let next = expr_list(token, nargs);
-            return ASTNode::new_EXPR_LIST(head, next);
-        }
-        Token::ARITH(ar) => {
-            if *ar != String::from("-") {
+        Token::RPAREN => return ASTNode::NULL,
+        Token::STRCONST(_) => {}
+        _ => {
+            if !starts_exp(token) {
                 error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_expr_list");
             }
-            let head = addsub_exp(token);
-            *nargs = *nargs + 1;
-            let next = expr_list(token, nargs);
-            return ASTNode::new_EXPR_LIST(head, next);
         }
-        Token::RPAREN => return ASTNode::NULL,
-        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_expr_list"),
     }
-    return ASTNode::NULL;
+    let head = arg_exp(token);
+    *nargs = *nargs + 1;
+    let next = expr_list(token, nargs);
+    return ASTNode::new_EXPR_LIST(head, next);
 }
 
 // this checks the rule for expr_list
@@ -587,7 +939,7 @@ fn expr_list(token: &mut Token, nargs: &mut u32) -> ASTNode {
     match *token {
         Token::COMMA => {
             match_token(token, Token::COMMA);
-            let head = addsub_exp(token);
+            let head = arg_exp(token);
             *nargs = *nargs + 1;
             let next = expr_list(token, nargs);
             return ASTNode::new_EXPR_LIST(head, next);
@@ -598,196 +950,297 @@ fn expr_list(token: &mut Token, nargs: &mut u32) -> ASTNode {
     return ASTNode::NULL;
 }
 
-// this checks the rule for or_exp
-fn or_exp(token: &mut Token) -> ASTNode {
-    match token {
-        Token::ID(_) | Token::INTCONST(_) | Token::LPAREN => {
-            let left = and_exp(token);
-            return or_no_lr(token, left);
+// this checks the rule for arg_exp
+// a string literal is added to the constant pool
+fn arg_exp(token: &mut Token) -> ASTNode {
+    match token.clone() {
+        Token::STRCONST(s) => {
+            match_token(token, Token::STRCONST(s.clone()));
+            let label = symbols.lock().unwrap().add_string(&s);
+            return ASTNode::new_STRCONST(s, label);
         }
-        Token::ARITH(ar) => {
-            if *ar != String::from("-") {
-                error::print_err_rule(*scanner::line.lock().unwrap(), token, "or_exp");
+        _ => return or_exp(token),
+    }
+}
+
+// this checks the rule for opt_arith_exp
+fn opt_arith_exp(token: &mut Token) -> ASTNode {
+    match token {
+        Token::SEMI => return ASTNode::NULL,
+        _ => {
+            if !starts_exp(token) {
+                error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_arith_exp");
             }
-            let left = and_exp(token);
-            return or_no_lr(token, left);
+            return or_exp(token);
         }
-        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "or_exp"),
     }
-    return ASTNode::NULL;
+}
+
+// this checks the rule for or_exp
+// or_exp is the whole expression, with the lowest precedence
+fn or_exp(token: &mut Token) -> ASTNode {
+    if !starts_exp(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "or_exp");
+    }
+    let left = and_exp(token);
+    return or_no_lr(token, left);
 }
 
 // this checks the rule for or_no_lr
 fn or_no_lr(token: &mut Token, left: ASTNode) -> ASTNode {
-    match token {
-        Token::BOOL(bl) => {
-            if *bl != String::from("||") {
-                error::print_err_rule(*scanner::line.lock().unwrap(), token, "or_no_lr");
-            }
-            match_token(token, Token::BOOL(String::from("||")));
-            let and_expr = and_exp(token);
-            return or_no_lr(token, ASTNode::new_BOOL(String::from("||"), left, and_expr));
-        }
-        Token::RPAREN => return left,
-        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "or_no_lr"),
+    if *token == Token::BOOL(String::from("||")) {
+        match_token(token, Token::BOOL(String::from("||")));
+        let and_expr = and_exp(token);
+        return or_no_lr(token, ASTNode::new_BOOL(String::from("||"), left, and_expr));
+    }
+    if !follows_operand(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "or_no_lr");
     }
     return left;
 }
 
 // this checks the rule for and_exp
 fn and_exp(token: &mut Token) -> ASTNode {
-    match token {
-        Token::ID(_) | Token::INTCONST(_) | Token::LPAREN => {
-            let bool_expr = bool_exp(token);
-            return and_no_lr(token, bool_expr);
-        }
-        Token::ARITH(ar) => {
-            if *ar != String::from("-") {
-                error::print_err_rule(*scanner::line.lock().unwrap(), token, "and_exp");
-            }
-            let bool_expr = bool_exp(token);
-            return and_no_lr(token, bool_expr);
-        }
-        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "and_exp"),
+    if !starts_exp(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "and_exp");
     }
-    return ASTNode::NULL;
+    let left = bor_exp(token);
+    return and_no_lr(token, left);
 }
 
 // this checks the rule for and_no_lr
 fn and_no_lr(token: &mut Token, left: ASTNode) -> ASTNode {
-    match token {
-        Token::BOOL(bl) => {
-            if *bl != String::from("&&") {
-                error::print_err_rule(*scanner::line.lock().unwrap(), token, "and_no_lr");
+    if *token == Token::BOOL(String::from("&&")) {
+        match_token(token, Token::BOOL(String::from("&&")));
+        let bor_expr = bor_exp(token);
+        return and_no_lr(token, ASTNode::new_BOOL(String::from("&&"), left, bor_expr));
+    }
+    if !follows_operand(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "and_no_lr");
+    }
+    return left;
+}
+
+// this checks the rule for bor_exp
+fn bor_exp(token: &mut Token) -> ASTNode {
+    if !starts_exp(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "bor_exp");
+    }
+    let left = bxor_exp(token);
+    return bor_no_lr(token, left);
+}
+
+// this checks the rule for bor_no_lr
+fn bor_no_lr(token: &mut Token, left: ASTNode) -> ASTNode {
+    match token.clone() {
+        Token::ARITH(op) => match op.as_str() {
+            "|" => {
+                match_token(token, Token::ARITH(op.clone()));
+                let op2 = bxor_exp(token);
+                return bor_no_lr(token, ASTNode::new_ARITH(op, left, op2));
             }
-            match_token(token, Token::BOOL(String::from("&&")));
-            let bool_expr = bool_exp(token);
-            return and_no_lr(
-                token,
-                ASTNode::new_BOOL(String::from("&&"), left, bool_expr),
-            );
-        }
-        Token::RPAREN => return left,
-        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "and_no_lr"),
+            _ => {}
+        },
+        _ => {}
+    }
+    if !follows_operand(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "bor_no_lr");
     }
     return left;
 }
 
-// this checks the rule for bool_exp
-fn bool_exp(token: &mut Token) -> ASTNode {
-    match token {
-        Token::ID(_) | Token::INTCONST(_) | Token::LPAREN => {
-            let op1 = addsub_exp(token);
-            let op = relop(token);
-            let op2 = addsub_exp(token);
-            return ASTNode::new_BOOL(op, op1, op2);
-        }
-        Token::ARITH(ar) => {
-            if *ar != String::from("-") {
-                error::print_err_rule(*scanner::line.lock().unwrap(), token, "bool_exp");
+// this checks the rule for bxor_exp
+fn bxor_exp(token: &mut Token) -> ASTNode {
+    if !starts_exp(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "bxor_exp");
+    }
+    let left = band_exp(token);
+    return bxor_no_lr(token, left);
+}
+
+// this checks the rule for bxor_no_lr
+fn bxor_no_lr(token: &mut Token, left: ASTNode) -> ASTNode {
+    match token.clone() {
+        Token::ARITH(op) => match op.as_str() {
+            "^" => {
+                match_token(token, Token::ARITH(op.clone()));
+                let op2 = band_exp(token);
+                return bxor_no_lr(token, ASTNode::new_ARITH(op, left, op2));
             }
-            let op1 = addsub_exp(token);
-            let op = relop(token);
-            let op2 = addsub_exp(token);
-            return ASTNode::new_BOOL(op, op1, op2);
-        }
-        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "bool_exp"),
+            _ => {}
+        },
+        _ => {}
     }
-    return ASTNode::NULL;
+    if !follows_operand(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "bxor_no_lr");
+    }
+    return left;
 }
 
-// this checks the rule for opt_arith_exp
-fn opt_arith_exp(token: &mut Token) -> ASTNode {
-    match token {
-        Token::ID(_) | Token::INTCONST(_) | Token::LPAREN => {
-            return addsub_exp(token);
-        }
-        Token::ARITH(ar) => {
-            if *ar != String::from("-") {
-                error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_arith_exp");
+// this checks the rule for band_exp
+fn band_exp(token: &mut Token) -> ASTNode {
+    if !starts_exp(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "band_exp");
+    }
+    let left = eq_exp(token);
+    return band_no_lr(token, left);
+}
+
+// this checks the rule for band_no_lr
+fn band_no_lr(token: &mut Token, left: ASTNode) -> ASTNode {
+    match token.clone() {
+        Token::ARITH(op) => match op.as_str() {
+            "&" => {
+                match_token(token, Token::ARITH(op.clone()));
+                let op2 = eq_exp(token);
+                return band_no_lr(token, ASTNode::new_ARITH(op, left, op2));
             }
-            return addsub_exp(token);
-        }
-        Token::SEMI => return ASTNode::NULL,
-        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "opt_arith_exp"),
+            _ => {}
+        },
+        _ => {}
     }
-    return ASTNode::NULL;
+    if !follows_operand(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "band_no_lr");
+    }
+    return left;
+}
+
+// this checks the rule for eq_exp
+fn eq_exp(token: &mut Token) -> ASTNode {
+    if !starts_exp(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "eq_exp");
+    }
+    let left = rel_exp(token);
+    return eq_no_lr(token, left);
+}
+
+// this checks the rule for eq_no_lr
+fn eq_no_lr(token: &mut Token, left: ASTNode) -> ASTNode {
+    match token.clone() {
+        Token::BOOL(op) => match op.as_str() {
+            "==" | "!=" => {
+                match_token(token, Token::BOOL(op.clone()));
+                let op2 = rel_exp(token);
+                return eq_no_lr(token, ASTNode::new_BOOL(op, left, op2));
+            }
+            _ => {}
+        },
+        _ => {}
+    }
+    if !follows_operand(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "eq_no_lr");
+    }
+    return left;
+}
+
+// this checks the rule for rel_exp
+fn rel_exp(token: &mut Token) -> ASTNode {
+    if !starts_exp(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "rel_exp");
+    }
+    let left = shift_exp(token);
+    return rel_no_lr(token, left);
+}
+
+// this checks the rule for rel_no_lr
+fn rel_no_lr(token: &mut Token, left: ASTNode) -> ASTNode {
+    match token.clone() {
+        Token::BOOL(op) => match op.as_str() {
+            "<" | "<=" | ">" | ">=" => {
+                match_token(token, Token::BOOL(op.clone()));
+                let op2 = shift_exp(token);
+                return rel_no_lr(token, ASTNode::new_BOOL(op, left, op2));
+            }
+            _ => {}
+        },
+        _ => {}
+    }
+    if !follows_operand(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "rel_no_lr");
+    }
+    return left;
+}
+
+// this checks the rule for shift_exp
+fn shift_exp(token: &mut Token) -> ASTNode {
+    if !starts_exp(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "shift_exp");
+    }
+    let left = addsub_exp(token);
+    return shift_no_lr(token, left);
+}
+
+// this checks the rule for shift_no_lr
+fn shift_no_lr(token: &mut Token, left: ASTNode) -> ASTNode {
+    match token.clone() {
+        Token::ARITH(op) => match op.as_str() {
+            "<<" | ">>" => {
+                match_token(token, Token::ARITH(op.clone()));
+                let op2 = addsub_exp(token);
+                return shift_no_lr(token, ASTNode::new_ARITH(op, left, op2));
+            }
+            _ => {}
+        },
+        _ => {}
+    }
+    if !follows_operand(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "shift_no_lr");
+    }
+    return left;
 }
 
 // this checks the rule for addsub_exp
 fn addsub_exp(token: &mut Token) -> ASTNode {
-    match token {
-        Token::ID(_) | Token::INTCONST(_) | Token::LPAREN => {
-            let left = muldiv_exp(token);
-            return addsub_no_lr(token, left);
-        }
-        Token::ARITH(ar) => {
-            if *ar != String::from("-") {
-                error::print_err_rule(*scanner::line.lock().unwrap(), token, "addsub_exp");
-            }
-            let left = muldiv_exp(token);
-            return addsub_no_lr(token, left);
-        }
-        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "addsub_exp"),
+    if !starts_exp(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "addsub_exp");
     }
-    return ASTNode::NULL;
+    let left = muldiv_exp(token);
+    return addsub_no_lr(token, left);
 }
 
 // this checks the rule for addsub_no_lr
 fn addsub_no_lr(token: &mut Token, left: ASTNode) -> ASTNode {
     match token.clone() {
-        Token::ARITH(ar) => {
-            if *ar == String::from("+") {
-                match_token(token, Token::ARITH(String::from("+")));
-            } else if *ar == String::from("-") {
-                match_token(token, Token::ARITH(String::from("-")));
-            } else {
-                error::print_err_rule(*scanner::line.lock().unwrap(), token, "addsub_no_lr");
+        Token::ARITH(op) => match op.as_str() {
+            "+" | "-" => {
+                match_token(token, Token::ARITH(op.clone()));
+                let op2 = muldiv_exp(token);
+                return addsub_no_lr(token, ASTNode::new_ARITH(op, left, op2));
             }
-            let op2 = muldiv_exp(token);
-            return addsub_no_lr(token, ASTNode::new_ARITH(ar.clone(), left, op2));
-        }
-        Token::RPAREN | Token::COMMA | Token::SEMI | Token::BOOL(_) => return left,
-        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "addsub_no_lr"),
+            _ => {}
+        },
+        _ => {}
+    }
+    if !follows_operand(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "addsub_no_lr");
     }
     return left;
 }
 
 // this checks the rule for muldiv_exp
 fn muldiv_exp(token: &mut Token) -> ASTNode {
-    match token {
-        Token::ID(_) | Token::INTCONST(_) | Token::LPAREN => {
-            let left = arith_exp(token);
-            return muldiv_no_lr(token, left);
-        }
-        Token::ARITH(ar) => {
-            if *ar != String::from("-") {
-                error::print_err_rule(*scanner::line.lock().unwrap(), token, "muldiv_exp");
-            }
-            let left = arith_exp(token);
-            return muldiv_no_lr(token, left);
-        }
-        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "muldiv_exp"),
+    if !starts_exp(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "muldiv_exp");
     }
-    return ASTNode::NULL;
+    let left = arith_exp(token);
+    return muldiv_no_lr(token, left);
 }
 
 // this checks the rule for muldiv_no_lr
 fn muldiv_no_lr(token: &mut Token, left: ASTNode) -> ASTNode {
     match token.clone() {
-        Token::ARITH(ar) => {
-            if *ar == String::from("*") {
-                match_token(token, Token::ARITH(String::from("*")));
-            } else if *ar == String::from("/") {
-                match_token(token, Token::ARITH(String::from("/")));
-            } else {
-                return left;
+        Token::ARITH(op) => match op.as_str() {
+            "*" | "/" | "%" => {
+                match_token(token, Token::ARITH(op.clone()));
+                let op2 = arith_exp(token);
+                return muldiv_no_lr(token, ASTNode::new_ARITH(op, left, op2));
             }
-            let op2 = arith_exp(token);
-            return muldiv_no_lr(token, ASTNode::new_ARITH(ar.clone(), left, op2));
-        }
-        Token::RPAREN | Token::COMMA | Token::SEMI | Token::BOOL(_) => return left,
-        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "muldiv_no_lr"),
+            _ => {}
+        },
+        _ => {}
+    }
+    if !follows_operand(token) {
+        error::print_err_rule(*scanner::line.lock().unwrap(), token, "muldiv_no_lr");
     }
     return left;
 }
@@ -805,59 +1258,39 @@ fn arith_exp(token: &mut Token) -> ASTNode {
             match_token(token, Token::INTCONST(0));
             return ASTNode::new_INTCONST(val);
         }
+        Token::CHARCONST(val) => {
+            match_token(token, Token::CHARCONST('\0'));
+            return ASTNode::new_CHARCONST(val);
+        }
+        Token::INCDEC(_) => return prefix_inc_dec(token),
         Token::LPAREN => {
             match_token(token, Token::LPAREN);
-            let expr = addsub_exp(token);
+            let expr = or_exp(token);
             match_token(token, Token::RPAREN);
             return expr;
         }
-        Token::ARITH(ar) => {
-            if *ar != String::from("-") {
+        Token::ARITH(ar) => match ar.as_str() {
+            "-" => {
+                match_token(token, Token::ARITH(String::from("-")));
+                let expr = arith_exp(token);
+                return ASTNode::new_ARITH(String::from("UMINUS"), expr, ASTNode::NULL);
+            }
+            "~" => {
+                match_token(token, Token::ARITH(String::from("~")));
+                let expr = arith_exp(token);
+                return ASTNode::new_ARITH(String::from("~"), expr, ASTNode::NULL);
+            }
+            _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "arith_exp"),
+        },
+        Token::BOOL(bl) => {
+            if *bl != String::from("!") {
                 error::print_err_rule(*scanner::line.lock().unwrap(), token, "arith_exp");
             }
-            match_token(token, Token::ARITH(String::from("-")));
+            match_token(token, Token::BOOL(String::from("!")));
             let expr = arith_exp(token);
-            return ASTNode::new_ARITH(String::from("UMINUS"), expr, ASTNode::NULL);
+            return ASTNode::new_BOOL(String::from("!"), expr, ASTNode::NULL);
         }
         _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "arith_exp"),
     };
     return ASTNode::NULL;
 }
-
-// this checks the rule for relop
-fn relop(token: &mut Token) -> String {
-    match token.clone() {
-        Token::BOOL(op) => match op.as_str() {
-            ">" => {
-                match_token(token, Token::BOOL(String::from(">")));
-                return op.clone();
-            }
-            ">=" => {
-                match_token(token, Token::BOOL(String::from(">=")));
-                return op.clone();
-            }
-            "<" => {
-                match_token(token, Token::BOOL(String::from("<")));
-                return op.clone();
-            }
-            "<=" => {
-                match_token(token, Token::BOOL(String::from("<=")));
-                return op.clone();
-            }
-            "!=" => {
-                match_token(token, Token::BOOL(String::from("!=")));
-                return op.clone();
-            }
-            "==" => {
-                match_token(token, Token::BOOL(String::from("==")));
-                return op.clone();
-            }
-            _ => {
-                error::print_err_rule(*scanner::line.lock().unwrap(), token, "relop");
-                return String::new();
-            }
-        },
-        _ => error::print_err_rule(*scanner::line.lock().unwrap(), token, "relop"),
-    };
-    return String::new();
-}
@@ -65,32 +65,59 @@ pub fn get_token() -> Token {
         '}' => return Token::RBRACE,
         '(' => return Token::LPAREN,
         ')' => return Token::RPAREN,
+        '[' => return Token::LBRACKET,
+        ']' => return Token::RBRACKET,
         ',' => return Token::COMMA,
         ';' => return Token::SEMI,
-        '+' => return Token::ARITH(String::from("+")),
-        '-' => return Token::ARITH(String::from("-")),
-        '*' => return Token::ARITH(String::from("*")),
-        '/' => return Token::ARITH(String::from("/")),
+        '+' | '-' => {
+            let first = curr;
+            curr = next_char(false);
+            if curr == first {
+                return Token::INCDEC(format!("{}{}", first, first)); // pattern ++ or -- matched
+            }
+            if curr == '=' {
+                return Token::OPASSG(format!("{}=", first)); // pattern += or -= matched
+            }
+            unget_char();
+            return Token::ARITH(first.to_string());
+        }
+        '*' | '/' => {
+            let first = curr;
+            curr = next_char(false);
+            if curr == '=' {
+                return Token::OPASSG(format!("{}=", first)); // pattern *= or /= matched
+            }
+            unget_char();
+            return Token::ARITH(first.to_string());
+        }
+        '%' => return Token::ARITH(String::from("%")),
+        '^' => return Token::ARITH(String::from("^")),
+        '~' => return Token::ARITH(String::from("~")),
         '0'..='9' => return match_intconst(&mut curr), // this is the beginning of an intconst
-        'e' | 'i' | 'w' | 'r' => return match_kw(&mut curr), // need to check for the keywords
+        'b' | 'c' | 'd' | 'e' | 'f' | 'i' | 'v' | 'w' | 'r' => return match_kw(&mut curr), // need to check for the keywords
+        '\'' => return match_charconst(), // this is the beginning of a charconst
+        '"' => return match_strconst(), // this is the beginning of a strconst
         '|' => {
             curr = next_char(false);
             if curr != '|' {
-                error::print_err_ch(*line.lock().unwrap(), curr);
+                unget_char();
+                return Token::ARITH(String::from("|")); // pattern | matched
             }
             return Token::BOOL(String::from("||")); // pattern || matched
         }
         '&' => {
             curr = next_char(false);
             if curr != '&' {
-                error::print_err_ch(*line.lock().unwrap(), curr);
+                unget_char();
+                return Token::ARITH(String::from("&")); // pattern & matched
             }
             return Token::BOOL(String::from("&&")); // pattern && matched
         }
         '!' => {
             curr = next_char(false);
             if curr != '=' {
-                error::print_err_ch(*line.lock().unwrap(), curr);
+                unget_char();
+                return Token::BOOL(String::from("!")); // pattern ! matched
             }
             return Token::BOOL(String::from("!=")); // pattern != matched
         }
@@ -104,6 +131,9 @@ pub fn get_token() -> Token {
         }
         '<' => {
             curr = next_char(false);
+            if curr == '<' {
+                return Token::ARITH(String::from("<<")); // pattern << matched
+            }
             if curr != '=' {
                 unget_char();
                 return Token::BOOL(String::from("<"));
@@ -112,6 +142,9 @@ pub fn get_token() -> Token {
         }
         '>' => {
             curr = next_char(false);
+            if curr == '>' {
+                return Token::ARITH(String::from(">>")); // pattern >> matched
+            }
             if curr != '=' {
                 unget_char();
                 return Token::BOOL(String::from(">"));
@@ -148,6 +181,63 @@ fn match_intconst(curr: &mut char) -> Token {
     return Token::INTCONST(curr_int); // build the intconst
 }
 
+/*
+ * this method matches a charconst, along with the escapes \n, \t, \0, \\, \' and \"
+ */
+fn match_charconst() -> Token {
+    let mut curr = next_char(false);
+    if curr == '\'' || curr == '\n' {
+        error::print_err_ch(*line.lock().unwrap(), curr); // cannot have an empty charconst
+    }
+    if curr == '\\' {
+        curr = match_escape();
+    }
+    if !curr.is_ascii() {
+        error::print_err_ch(*line.lock().unwrap(), curr); // charconst must fit in a char
+    }
+    let close = next_char(false);
+    if close != '\'' {
+        error::print_err_ch(*line.lock().unwrap(), close); // charconst must be one char
+    }
+    return Token::CHARCONST(curr);
+}
+
+/*
+ * this method matches a strconst, which uses the same escapes as a charconst
+ */
+fn match_strconst() -> Token {
+    let mut s = String::new();
+    loop {
+        let mut curr = next_char(false);
+        if curr == '"' {
+            break;
+        }
+        if curr == '\n' {
+            error::print_err_ch(*line.lock().unwrap(), curr); // strconst cannot span lines
+        }
+        if curr == '\\' {
+            curr = match_escape();
+        }
+        s.push(curr);
+    }
+    return Token::STRCONST(s);
+}
+
+/*
+ * this method matches the char after a \ and returns the char it stands for
+ */
+fn match_escape() -> char {
+    let curr = next_char(false);
+    match curr {
+        'n' => return '\n',
+        't' => return '\t',
+        '0' => return '\0',
+        '\\' | '\'' | '"' => return curr,
+        _ => error::print_err_ch(*line.lock().unwrap(), curr), // unknown escape
+    }
+    return curr;
+}
+
 /*
  * this function matches the keywords supplied from C--
  */
@@ -156,6 +246,43 @@ fn match_kw(curr: &mut char) -> Token {
     sofar.push(*curr); // the string sofar needs the curr token
 
     match *curr {
+        'b' => return match_kw_rest(&mut sofar, "reak"), // this matches break!
+        'c' => {
+            *curr = next_char(false);
+            match *curr {
+                'h' => {
+                    sofar.push(*curr);
+                    return match_kw_rest(&mut sofar, "ar"); // this matches char!
+                }
+                'o' => {
+                    sofar.push(*curr);
+                    return match_kw_rest(&mut sofar, "ntinue"); // this matches continue!
+                }
+                _ => {
+                    unget_char();
+                    return match_id(&mut sofar); // need to match an id
+                }
+            }
+        }
+        'f' => {
+            *curr = next_char(false);
+            if *curr != 'o' {
+                unget_char();
+                return match_id(&mut sofar); // need to match an id
+            }
+            sofar.push(*curr);
+            *curr = next_char(false);
+            if *curr != 'r' {
+                unget_char();
+                return match_id(&mut sofar); // need to match an id
+            }
+            sofar.push(*curr);
+            *curr = peek_char();
+            if (*curr).is_alphanumeric() || *curr == '_' {
+                return match_id(&mut sofar); // need to match an id
+            }
+            return Token::KW(sofar); // this matches for!
+        }
         'i' => {
             *curr = next_char(false);
             match *curr {
@@ -187,6 +314,7 @@ fn match_kw(curr: &mut char) -> Token {
                 }
             }
         }
+        'd' => return match_kw_rest(&mut sofar, "o"), // this matches do!
         'e' => {
             *curr = next_char(false);
             if *curr != 'l' {
@@ -249,6 +377,7 @@ fn match_kw(curr: &mut char) -> Token {
             }
             return Token::KW(sofar); // this matches return!
         }
+        'v' => return match_kw_rest(&mut sofar, "oid"), // this matches void!
         'w' => {
             *curr = next_char(false);
             if *curr != 'h' {
@@ -288,6 +417,26 @@ fn match_kw(curr: &mut char) -> Token {
     }
 }
 
+/*
+ * this function matches the rest of a keyword after sofar, one char at a time,
+ * and falls back to an id as soon as a char does not match
+ */
+fn match_kw_rest(sofar: &mut String, rest: &str) -> Token {
+    for c in rest.chars() {
+        let curr = next_char(false);
+        if curr != c {
+            unget_char();
+            return match_id(sofar); // need to match an id
+        }
+        sofar.push(curr);
+    }
+    let curr = peek_char();
+    if curr.is_alphanumeric() || curr == '_' {
+        return match_id(sofar); // need to match an id
+    }
+    return Token::KW(sofar.to_string());
+}
+
 /*
     // C CODE
     char ch;
@@ -414,14 +563,20 @@ pub enum Token {
     EOF,
     ID(String),
     INTCONST(i32),
+    CHARCONST(char),
+    STRCONST(String),
     LPAREN,
     RPAREN,
     LBRACE,
     RBRACE,
+    LBRACKET,
+    RBRACKET,
     COMMA,
     SEMI,
     ASSG,
-    KW(String),    /* kwINT, kwIF, kwELSE, kwWHILE, kwRETURN */
-    ARITH(String), /* opADD, opSUB, opMUL, opDIV */
-    BOOL(String),  /* opEQ, opNE, opGT, opGE, opLT, opLE, opAND, opOR, */
+    OPASSG(String), /* opADDASSG, opSUBASSG, opMULASSG, opDIVASSG */
+    INCDEC(String), /* opINC, opDEC */
+    KW(String),    /* kwINT, kwCHAR, kwVOID, kwIF, kwELSE, kwWHILE, kwFOR, kwDO, kwRETURN, kwBREAK, kwCONTINUE */
+    ARITH(String), /* opADD, opSUB, opMUL, opDIV, opMOD, opBAND, opBOR, opBXOR, opBNOT, opSHL, opSHR */
+    BOOL(String),  /* opEQ, opNE, opGT, opGE, opLT, opLE, opAND, opOR, opNOT */
 }
@@ -11,22 +11,69 @@ use crate::error;
 static mut NULL_ENTRY: Entry = Entry::NULL;
 static mut NULL_ST: SymbolTable = SymbolTable::NULL;
 
+// this is the type of a var; chars and ints convert to each other implicitly as in C
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Type {
+    INT,
+    CHAR,
+    VOID, // only used as the return type of a function
+}
+
+// this is the number of bytes a var or an array of size elements takes up,
+// or None if that does not fit in a frame offset
+pub fn bytes(size: Option<u32>) -> Option<i32> {
+    match size {
+        Some(n) => match n.checked_mul(4) {
+            Some(b) if b <= i32::MAX as u32 => Some(b as i32),
+            _ => None,
+        },
+        None => Some(4),
+    }
+}
+
+// this is the label of the string constant at index in the pool. Globals and
+// functions are labeled _id, and no id can contain a '.', so these never clash
+fn string_label(index: usize) -> String {
+    format!("_str.{}", index)
+}
+
+// this puts the escapes back into a string so it can be printed as a literal
+pub fn escape(s: &str) -> String {
+    let mut out = String::new();
+    for c in s.chars() {
+        match c {
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\0' => out.push_str("\\0"),
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
 #[derive(PartialEq, Clone, Debug)]
 pub enum Entry {
     BODY_VAR {
         id: String,
+        ty: Type,
         var_num: u32,
+        size: Option<u32>, // the number of elements if this is an array
         frame_offset: i32,
         next: Option<Box<Entry>>,
     },
     PARAM_VAR {
         id: String,
+        ty: Type,
         param_num: u32,
         frame_offset: i32,
         next: Option<Box<Entry>>,
     },
     GLOBAL_VAR {
         id: String,
+        ty: Type,
+        size: Option<u32>, // the number of elements if this is an array
         next: Option<Box<Entry>>,
     },
     NULL,
@@ -34,28 +81,92 @@ pub enum Entry {
 }
 
 impl Entry {
-    fn new_global(id: &mut String) -> Entry {
+    fn new_global(id: &mut String, ty: Type, size: Option<u32>) -> Entry {
         Entry::GLOBAL_VAR {
             id: id.clone(),
+            ty,
+            size,
             next: None,
         }
     }
 
-    fn new_param(id: &mut String, param_num: u32) -> Entry {
+    fn new_param(id: &mut String, ty: Type, param_num: u32) -> Entry {
         Entry::PARAM_VAR {
             id: id.clone(),
+            ty,
             param_num,
             frame_offset: 4 * (param_num as i32 + 1) + 4,
             next: None,
         }
     }
 
-    fn new_body_var(id: &mut String, var_num: u32) -> Entry {
-        Entry::BODY_VAR {
+    // top is the frame_offset of the previous body var, so an array's
+    // elements sit between it and the previous var, with a[0] lowest.
+    // this returns None if the var does not fit in the frame
+    fn new_body_var(
+        id: &mut String,
+        ty: Type,
+        var_num: u32,
+        size: Option<u32>,
+        top: i32,
+    ) -> Option<Entry> {
+        let frame_offset = bytes(size).and_then(|b| top.checked_sub(b))?;
+        Some(Entry::BODY_VAR {
             id: id.clone(),
+            ty,
             var_num,
-            frame_offset: -4 * (var_num as i32 + 1),
+            size,
+            frame_offset,
             next: None,
+        })
+    }
+
+    pub fn get_type(&self) -> Type {
+        match self {
+            Entry::BODY_VAR { ty, .. }
+            | Entry::PARAM_VAR { ty, .. }
+            | Entry::GLOBAL_VAR { ty, .. } => *ty,
+            _ => Type::INT,
+        }
+    }
+
+    pub fn is_array(&self) -> bool {
+        match self {
+            Entry::BODY_VAR { size, .. } | Entry::GLOBAL_VAR { size, .. } => size.is_some(),
+            _ => false,
+        }
+    }
+
+    // this collects the id of every entry in this list
+    fn ids(&self, out: &mut Vec<String>) {
+        match self {
+            Entry::BODY_VAR { id, next, .. }
+            | Entry::PARAM_VAR { id, next, .. }
+            | Entry::GLOBAL_VAR { id, next, .. } => {
+                out.push(id.clone());
+                if let Some(n) = next {
+                    n.ids(out);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // this finds the entry with id in this list
+    fn lookup(&self, id: &String) -> Option<&Entry> {
+        match self {
+            Entry::BODY_VAR { id: var_id, next, .. }
+            | Entry::PARAM_VAR { id: var_id, next, .. }
+            | Entry::GLOBAL_VAR { id: var_id, next, .. } => {
+                if *id == *var_id {
+                    return Some(self);
+                }
+                match next {
+                    Some(n) => n.lookup(id),
+                    None => None,
+                }
+            }
+            _ => None,
         }
     }
 
@@ -68,48 +179,60 @@ impl Entry {
         }
     }
 
-    fn add_global(&mut self, id: &mut String) {
+    fn add_global(&mut self, id: &mut String, ty: Type, size: Option<u32>) {
         match self {
             Entry::GLOBAL_VAR {
                 next: ref mut g, ..
             } => match g {
-                Some(ref mut n) => n.add_global(id),
-                None => *g = Some(Box::new(Entry::new_global(id))),
+                Some(ref mut n) => n.add_global(id, ty, size),
+                None => *g = Some(Box::new(Entry::new_global(id, ty, size))),
             },
             _ => std::process::exit(1),
         }
     }
 
-    fn add_param(&mut self, id: &mut String) {
-        self.priv_add_param(id, 1);
+    fn add_param(&mut self, id: &mut String, ty: Type) {
+        self.priv_add_param(id, ty, 1);
     }
 
-    fn priv_add_param(&mut self, id: &mut String, param_num: u32) {
+    fn priv_add_param(&mut self, id: &mut String, ty: Type, param_num: u32) {
         match self {
             Entry::PARAM_VAR {
                 next: ref mut g, ..
             } => match g {
-                Some(ref mut n) => n.priv_add_param(id, param_num + 1),
-                None => *g = Some(Box::new(Entry::new_param(id, param_num))),
+                Some(ref mut n) => n.priv_add_param(id, ty, param_num + 1),
+                None => *g = Some(Box::new(Entry::new_param(id, ty, param_num))),
             },
             _ => std::process::exit(1),
         }
     }
 
-    fn add_body_var(&mut self, id: &mut String) {
-        self.priv_add_body_var(id, 1);
+    fn add_body_var(&mut self, id: &mut String, ty: Type, size: Option<u32>) -> bool {
+        return self.priv_add_body_var(id, ty, 1, size);
     }
 
-    fn priv_add_body_var(&mut self, id: &mut String, var_num: u32) {
+    fn priv_add_body_var(
+        &mut self,
+        id: &mut String,
+        ty: Type,
+        var_num: u32,
+        size: Option<u32>,
+    ) -> bool {
         match self {
             Entry::BODY_VAR {
-                next: ref mut g, ..
+                next: ref mut g,
+                frame_offset,
+                ..
             } => match g {
-                Some(ref mut n) => n.priv_add_body_var(id, var_num + 1),
-                None => *g = Some(Box::new(Entry::new_body_var(id, var_num))),
+                Some(ref mut n) => return n.priv_add_body_var(id, ty, var_num + 1, size),
+                None => match Entry::new_body_var(id, ty, var_num, size, *frame_offset) {
+                    Some(e) => *g = Some(Box::new(e)),
+                    None => return false,
+                },
             },
             _ => std::process::exit(1),
         }
+        return true;
     }
 
     fn var_def(&mut self, id: &mut String) -> bool {
@@ -208,11 +331,13 @@ pub enum SymbolTable {
     GLOBAL {
         globals: Option<Box<Entry>>,
         functions: Option<Box<SymbolTable>>,
+        strings: Vec<String>, // the string constant pool, where index n has the label _str.n
     },
     FUNCTION {
         ntemps: u32,
         nparams: u32,
         name: String,
+        ret: Type,
         params: Option<Box<Entry>>,
         body_vars: Option<Box<Entry>>,
         next: Option<Box<SymbolTable>>,
@@ -225,9 +350,49 @@ impl SymbolTable {
         SymbolTable::GLOBAL {
             globals: None,
             functions: None,
+            strings: Vec::new(),
+        }
+    }
+
+    /*
+     * this adds a string literal to the constant pool, reusing the label of
+     * an equal string if there is one
+     *  val: &String -- the string, with its escapes already replaced
+     * returns: String -- the label of the string
+     */
+    pub fn add_string(&mut self, val: &String) -> String {
+        match self {
+            SymbolTable::GLOBAL { strings, .. } => {
+                let index = match strings.iter().position(|s| *s == *val) {
+                    Some(i) => i,
+                    None => {
+                        strings.push(val.clone());
+                        strings.len() - 1
+                    }
+                };
+                return string_label(index);
+            }
+            _ => {
+                eprintln!("cannot add a string to a function table.");
+                std::process::exit(1);
+            }
         }
     }
 
+    /*
+     * this builds the data section that holds the string constant pool
+     * returns: String -- one .asciiz directive per string
+     */
+    pub fn string_data(&self) -> String {
+        let mut out = String::new();
+        if let SymbolTable::GLOBAL { strings, .. } = self {
+            for (i, s) in strings.iter().enumerate() {
+                out.push_str(&format!("{}: .asciiz \"{}\"\n", string_label(i), escape(s)));
+            }
+        }
+        out
+    }
+
     pub fn string(&mut self) -> String {
         match self {
             SymbolTable::GLOBAL { .. } => return String::new(),
@@ -237,18 +402,19 @@ impl SymbolTable {
         return String::new();
     }
 
-    pub fn add_function(&mut self, name: &mut String, nparams: &mut u32) {
+    pub fn add_function(&mut self, name: &mut String, nparams: &mut u32, ret: Type) {
         match self {
             SymbolTable::GLOBAL {
                 functions: ref mut f,
                 ..
             } => match f {
-                Some(ref mut b) => return b.add_function(name, nparams),
+                Some(ref mut b) => return b.add_function(name, nparams, ret),
                 None => {
                     *f = Some(Box::new(SymbolTable::FUNCTION {
                         ntemps: 0,
                         nparams: *nparams,
                         name: name.clone(),
+                        ret,
                         params: None,
                         body_vars: None,
                         next: None,
@@ -258,12 +424,13 @@ impl SymbolTable {
             SymbolTable::FUNCTION {
                 next: ref mut f, ..
             } => match f {
-                Some(ref mut b) => return b.add_function(name, nparams),
+                Some(ref mut b) => return b.add_function(name, nparams, ret),
                 None => {
                     *f = Some(Box::new(SymbolTable::FUNCTION {
                         ntemps: 0,
                         nparams: *nparams,
                         name: name.clone(),
+                        ret,
                         params: None,
                         body_vars: None,
                         next: None,
@@ -273,24 +440,24 @@ impl SymbolTable {
             _ => error::print_err_msg("string NULL"),
         };
     }
-    pub fn add_global(&mut self, id: &mut String) {
+    pub fn add_global(&mut self, id: &mut String, ty: Type, size: Option<u32>) {
         match self {
             SymbolTable::GLOBAL {
                 globals: ref mut g, ..
             } => match g {
-                Some(ref mut b) => return b.add_global(id),
-                None => *g = Some(Box::new(Entry::new_global(id))),
+                Some(ref mut b) => return b.add_global(id, ty, size),
+                None => *g = Some(Box::new(Entry::new_global(id, ty, size))),
             },
             _ => std::process::exit(1),
         }
     }
-    pub fn add_param(&mut self, id: &mut String) {
+    pub fn add_param(&mut self, id: &mut String, ty: Type) {
         match self {
             SymbolTable::GLOBAL {
                 functions: ref mut f,
                 ..
             } => match f {
-                Some(ref mut b) => return b.add_param(id),
+                Some(ref mut b) => return b.add_param(id, ty),
                 None => {
                     eprintln!("cannot add param when no function has been defined.");
                     std::process::exit(1);
@@ -301,22 +468,24 @@ impl SymbolTable {
                 params: ref mut p,
                 ..
             } => match f {
-                Some(ref mut b) => return b.add_param(id),
+                Some(ref mut b) => return b.add_param(id, ty),
                 None => match p {
-                    Some(ref mut b) => b.add_param(id),
-                    None => *p = Some(Box::new(Entry::new_param(id, 0))),
+                    Some(ref mut b) => b.add_param(id, ty),
+                    None => *p = Some(Box::new(Entry::new_param(id, ty, 0))),
                 },
             },
             _ => error::print_err_msg("string NULL"),
         };
     }
-    pub fn add_body_var(&mut self, id: &mut String) {
+    // this adds a body var to the function being defined, and returns false
+    // if the body vars no longer fit in its frame
+    pub fn add_body_var(&mut self, id: &mut String, ty: Type, size: Option<u32>) -> bool {
         match self {
             SymbolTable::GLOBAL {
                 functions: ref mut f,
                 ..
             } => match f {
-                Some(ref mut b) => return b.add_body_var(id),
+                Some(ref mut b) => return b.add_body_var(id, ty, size),
                 None => {
                     eprintln!("cannot add body_var when no function has been defined.");
                     std::process::exit(1);
@@ -324,17 +493,21 @@ impl SymbolTable {
             },
             SymbolTable::FUNCTION {
                 next: ref mut f,
-                params: ref mut p,
+                body_vars: ref mut v,
                 ..
             } => match f {
-                Some(ref mut b) => return b.add_body_var(id),
-                None => match p {
-                    Some(ref mut b) => b.add_body_var(id),
-                    None => *p = Some(Box::new(Entry::new_body_var(id, 0))),
+                Some(ref mut b) => return b.add_body_var(id, ty, size),
+                None => match v {
+                    Some(ref mut b) => return b.add_body_var(id, ty, size),
+                    None => match Entry::new_body_var(id, ty, 0, size, 0) {
+                        Some(e) => *v = Some(Box::new(e)),
+                        None => return false,
+                    },
                 },
             },
             _ => error::print_err_msg("string NULL"),
         };
+        return true;
     }
     pub unsafe fn get_body_var(&mut self, id: &mut String) -> &mut Entry {
         match self {
@@ -446,6 +619,7 @@ impl SymbolTable {
             SymbolTable::GLOBAL {
                 functions: ref mut f,
                 globals: ref mut g,
+                ..
             } => {
                 match g {
                     Some(ref mut b) => {
@@ -494,6 +668,134 @@ impl SymbolTable {
         };
         return &mut NULL_ENTRY;
     }
+    /*
+     * this finds the entry for id, where the params and body vars of the
+     * current function hide the globals
+     */
+    pub fn lookup(&self, id: &String) -> Option<&Entry> {
+        match self {
+            SymbolTable::GLOBAL {
+                functions: f,
+                globals: g,
+                ..
+            } => {
+                if let Some(b) = f {
+                    if let Some(e) = b.lookup(id) {
+                        return Some(e);
+                    }
+                }
+                match g {
+                    Some(b) => return b.lookup(id),
+                    None => return None,
+                }
+            }
+            SymbolTable::FUNCTION {
+                next: f,
+                params: p,
+                body_vars: v,
+                ..
+            } => match f {
+                Some(b) => return b.lookup(id),
+                None => {
+                    if let Some(b) = p {
+                        if let Some(e) = b.lookup(id) {
+                            return Some(e);
+                        }
+                    }
+                    match v {
+                        Some(b) => return b.lookup(id),
+                        None => return None,
+                    }
+                }
+            },
+            _ => return None,
+        }
+    }
+
+    // this checks if id names an array
+    pub fn is_array(&mut self, id: &mut String) -> bool {
+        match self.lookup(id) {
+            Some(e) => return e.is_array(),
+            None => return false,
+        }
+    }
+
+    // this finds the return type of the function being defined, which is the last one added
+    pub fn ret_type(&self) -> Type {
+        match self {
+            SymbolTable::GLOBAL { functions: f, .. } => match f {
+                Some(b) => return b.ret_type(),
+                None => return Type::INT,
+            },
+            SymbolTable::FUNCTION { next: f, ret, .. } => match f {
+                Some(b) => return b.ret_type(),
+                None => return *ret,
+            },
+            _ => return Type::INT,
+        }
+    }
+
+    // this finds the return type of a function, if it has been defined
+    pub fn fn_ret_type(&self, id: &String, nparams: u32) -> Option<Type> {
+        match self {
+            SymbolTable::GLOBAL { functions: f, .. } => match f {
+                Some(b) => return b.fn_ret_type(id, nparams),
+                None => return None,
+            },
+            SymbolTable::FUNCTION {
+                next: f,
+                name,
+                nparams: p,
+                ret,
+                ..
+            } => {
+                if *id == *name && *p == nparams {
+                    return Some(*ret);
+                }
+                match f {
+                    Some(b) => return b.fn_ret_type(id, nparams),
+                    None => return None,
+                }
+            }
+            _ => return None,
+        }
+    }
+
+    // this finds the params and body vars of a function, which hide the globals with the same ids
+    pub fn fn_locals(&self, id: &str, nparams: u32) -> Vec<String> {
+        let mut out = Vec::new();
+        match self {
+            SymbolTable::GLOBAL { functions: f, .. } => {
+                if let Some(b) = f {
+                    return b.fn_locals(id, nparams);
+                }
+            }
+            SymbolTable::FUNCTION {
+                next: f,
+                name,
+                nparams: p,
+                params,
+                body_vars,
+                ..
+            } => {
+                if *name == id && *p == nparams {
+                    if let Some(b) = params {
+                        b.ids(&mut out);
+                    }
+                    if let Some(b) = body_vars {
+                        b.ids(&mut out);
+                    }
+                    return out;
+                }
+                if let Some(b) = f {
+                    return b.fn_locals(id, nparams);
+                }
+            }
+            _ => {}
+        }
+        return out;
+    }
+
     pub fn global_var_def(&mut self, id: &mut String) -> bool {
         match self {
             SymbolTable::GLOBAL {
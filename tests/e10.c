@@ -0,0 +1,8 @@
+// break outside of a loop
+int main() {
+	int a;
+	a = 1;
+	if (a > 0)
+		break;
+	return a;
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+// value returned from a void function
+void f() {
+	return 1;
+}
+int main() {
+	f();
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+// void call used as a value
+void f() {
+}
+int main() {
+	int a;
+	a = f() + 1;
+}
\ No newline at end of file
@@ -0,0 +1,5 @@
+// increment of an array without an index
+int main() {
+	int a[3];
+	a++;
+}
\ No newline at end of file
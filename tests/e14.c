@@ -0,0 +1,5 @@
+// local array too large for the stack frame
+int main() {
+	int a[1000000000];
+	a[0] = 1;
+}
\ No newline at end of file
@@ -0,0 +1,5 @@
+// second declarator does not fit in the stack frame
+int main() {
+	int a[536870911], b[536870911];
+	a[0] = 1;
+}
\ No newline at end of file
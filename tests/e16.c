@@ -0,0 +1,5 @@
+// char literal outside of ascii
+int main() {
+	char c;
+	c = 'é';
+}
\ No newline at end of file
@@ -0,0 +1,6 @@
+// index a scalar
+int main() {
+	int a;
+	a[0] = 1;
+	return a;
+}
\ No newline at end of file
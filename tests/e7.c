@@ -0,0 +1,7 @@
+// use an array as a scalar
+int a[4];
+int main() {
+	int b;
+	b = a + 1;
+	return b;
+}
\ No newline at end of file
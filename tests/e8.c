@@ -0,0 +1,6 @@
+// char literal with two chars
+int main() {
+	char c;
+	c = 'ab';
+	return c;
+}
\ No newline at end of file
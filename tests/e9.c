@@ -0,0 +1,5 @@
+// string passed to a user function
+int f(int a) { return a; }
+int main() {
+	f("oops");
+}
\ No newline at end of file
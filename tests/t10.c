@@ -0,0 +1,29 @@
+/* void functions */
+
+int count;
+
+void tick()
+{
+    count = count + 1;
+}
+
+void report(int n)
+{
+    if (n < 0)
+        return;
+    println(n);
+}
+
+int next()
+{
+    tick();
+    return count;
+}
+
+int main()
+{
+    count = 0;
+    tick();
+    report(next());
+    return;
+}
\ No newline at end of file
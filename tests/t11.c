@@ -0,0 +1,21 @@
+/* boolean and relational expressions as values */
+
+int max(int a, int b)
+{
+    return a > b;
+}
+
+int main()
+{
+    int x;
+    int y;
+    int flags[3];
+    x = 3 < 4;
+    y = x == 1 && !(x != 1) || 0;
+    flags[x < 2] = !y;
+    println(max(x, y) == (y >= x));
+    println(!x + 1 < 2);
+    while (!(x >= 10))
+        x = x + 1;
+    return x <= y;
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+/* bitwise and modulo operators */
+
+int hash(int key, int size)
+{
+    int h;
+    h = key ^ (key >> 3);
+    h = h * 31 + (h << 2) & 255;
+    return h % size;
+}
+
+int main()
+{
+    int x;
+    int mask;
+    x = 5 & 3 | 8 ^ 2;
+    mask = ~0 << 4;
+    if (x & 1 == 1 || (mask | x) != 0)
+        println(hash(x, 7));
+    println(x % 3 * 2 >> 1 < 4);
+    return -x & ~mask;
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+/* compound assignment and increment / decrement */
+
+int total;
+
+int main()
+{
+    int i;
+    int j;
+    int a[4];
+    total = 0;
+    for (i = 0; i < 4; i++)
+        a[i] = i;
+    for (j = 3; j >= 0; --j) {
+        total += a[j] * 2;
+        total -= 1;
+    }
+    total *= 3;
+    total /= 2;
+    ++total;
+    total--;
+    i = 0;
+    a[i++] = --j + total++;
+    println(i-- - -j);
+    println(i+++j);
+    return total;
+}
\ No newline at end of file
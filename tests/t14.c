@@ -0,0 +1,16 @@
+/* a global and a function whose labels look like the string pool's */
+
+int str0;
+
+int str1(int x)
+{
+    return x + str0;
+}
+
+int main()
+{
+    str0 = 1;
+    print_string("first\n");
+    print_string("second\n");
+    println(str1(2));
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+/* dead functions and globals, for --elim_dead */
+
+int used, unused, hidden;
+
+int wow() {}
+
+int helper(int x)
+{
+    return x + used;
+}
+
+int orphan(int y)
+{
+    return helper(y) + unused;
+}
+
+int local()
+{
+    int hidden;
+    hidden = 3;
+    return hidden;
+}
+
+int main()
+{
+    used = 1;
+    println(helper(2) + local());
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+/* side effects, for --dump_effects */
+
+int count, limit;
+
+int square(int x)
+{
+    return x * x;
+}
+
+int under(int x)
+{
+    if (x < limit)
+        return 1;
+    return 0;
+}
+
+int bump()
+{
+    count = count + 1;
+    return count;
+}
+
+int shadow(int x)
+{
+    int count;
+    count = x + 1;
+    return count;
+}
+
+int fact(int n)
+{
+    if (n <= 1)
+        return 1;
+    return n * fact(n - 1);
+}
+
+int main()
+{
+    limit = 10;
+    while (under(bump()) == 1)
+        println(square(count));
+    println(fact(5));
+    println(shadow(count));
+}
\ No newline at end of file
@@ -0,0 +1,29 @@
+/* one-dimensional arrays */
+
+int data[10], n;
+
+int sum(int len)
+{
+    int i, total;
+    int seen[4];
+    i = 0;
+    total = 0;
+    while (i < len)
+    {
+        total = total + data[i];
+        seen[i / 4] = data[i * 1];
+        i = i + 1;
+    }
+    return total;
+}
+
+int main()
+{
+    n = 0;
+    while (n < 10)
+    {
+        data[n] = n * n;
+        n = n + 1;
+    }
+    println(sum(data[2] - 1));
+}
\ No newline at end of file
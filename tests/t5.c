@@ -0,0 +1,29 @@
+/* chars, char literals and escapes */
+
+char newline, text[6];
+
+int upper(char c)
+{
+    if (c >= 'a' && c <= 'z')
+        return c - 'a' + 'A';
+    return c;
+}
+
+int main()
+{
+    int i;
+    char tab, quote;
+    newline = '\n';
+    tab = '\t';
+    quote = '\'';
+    text[0] = 'h';
+    text[1] = 'i';
+    text[2] = '\0';
+    i = 0;
+    while (text[i] != '\0')
+    {
+        println(upper(text[i]));
+        i = i + 1;
+    }
+    println(newline + tab + quote + '\\');
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+/* string literals and print_string */
+
+int main()
+{
+    int x;
+    x = 42;
+    print_string("x is:\t");
+    println(x);
+    print_string("she said \"hi\"\n");
+    print_string("x is:\t");
+}
\ No newline at end of file
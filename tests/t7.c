@@ -0,0 +1,20 @@
+/* for loops */
+
+int total;
+
+int main()
+{
+    int i, j;
+    int squares[10];
+    total = 0;
+    for (i = 0; i < 10; i = i + 1)
+        squares[i] = i * i;
+    for (i = 0; i < 10 && total < 100; i = i + 1)
+    {
+        for (j = 0; j <= i; j = j + 1)
+            total = total + squares[j];
+    }
+    for (i = 9; i >= 0; println(i))
+        i = i - 1;
+    println(total);
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+/* break and continue */
+
+int main()
+{
+    int i, found, breaker, cont;
+    int data[8];
+    found = 0 - 1;
+    for (i = 0; i < 8; i = i + 1)
+        data[i] = i * 3;
+    for (i = 0; i < 8; i = i + 1)
+    {
+        if (data[i] / 2 * 2 != data[i])
+            continue;
+        if (data[i] > 10)
+        {
+            found = i;
+            break;
+        }
+    }
+    i = 0;
+    while (i < 100)
+    {
+        i = i + 1;
+        while (i > 50)
+            break;
+        if (i < 90)
+            continue;
+        breaker = i;
+        cont = breaker;
+    }
+    println(found);
+}
\ No newline at end of file
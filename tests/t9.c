@@ -0,0 +1,22 @@
+/* do-while loops */
+
+int main()
+{
+    int n, digits, done;
+    n = 12345;
+    digits = 0;
+    do
+    {
+        digits = digits + 1;
+        n = n / 10;
+    } while (n > 0);
+    done = 0;
+    do
+        if (digits > 3)
+            break;
+        else
+            digits = digits + 1;
+    while (done == 0);
+    do done = 1; while (done == 0);
+    println(digits);
+}
\ No newline at end of file